@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+use app::geometry::{Finger, Geometry};
+use app::history::{Count, History};
+use app::layout::Layout;
+
+
+// normal quantile for a 95 % confidence
+static Z: f64 = 1.96;
+
+// samples giving a confidence of one half
+static HALF_CONFIDENCE_SAMPLES: f64 = 10.0;
+
+// weight of the slowness against the error rate in the score
+static LATENCY_WEIGHT: f64 = 0.5;
+
+
+//---
+/// What can be weak.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Item {
+    Char(char),
+    Bigram(char, char),
+    Finger(Finger)
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let shown = |c: char| match c {
+            ' ' => '\u{2423}',
+            c => c
+        };
+        match *self {
+            Item::Char(c) => write!(f, "{}", shown(c)),
+            Item::Bigram(a, b) => write!(f, "{}{}", shown(a), shown(b)),
+            Item::Finger(finger) => write!(f, "{}", finger.name())
+        }
+    }
+}
+
+
+//---
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weakness {
+    pub item: Item,
+    // keystrokes where it was expected
+    pub samples: usize,
+    // ratio of missed keystrokes, from 0 to 1
+    pub error_rate: f64,
+    // lowest likely error rate, the lower bound of its Wilson interval
+    pub min_error_rate: f64,
+    // mean time to type it right
+    pub latency: Option<Duration>,
+    // from 0 to 1, growing with the samples
+    pub confidence: f64,
+    // the higher, the weaker
+    pub score: f64
+}
+
+
+//---
+/// Weakest items of the history, the weakest first.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub chars: Vec<Weakness>,
+    pub bigrams: Vec<Weakness>,
+    pub fingers: Vec<Weakness>,
+    // layouts of sessions left out of the fingers, which cannot be found
+    pub unresolved: Vec<String>
+}
+
+/// Weakest items of the history, the fingers being those of the keys of
+/// `geometry`, with the layout of each session found by `resolve`.
+pub fn analyze(history: &History, geometry: &Geometry, resolve: &dyn Fn(&str) -> Option<Layout>) -> Report {
+    // fingers depend on the layout of each session
+    let mut layouts: BTreeMap<&str, Option<Layout>> = BTreeMap::new();
+    let mut fingers: BTreeMap<Item, Count> = BTreeMap::new();
+    for entry in history.entries.iter() {
+        let layout = match *layouts.entry(&entry.layout).or_insert_with(|| resolve(&entry.layout)) {
+            Some(ref layout) => layout,
+            None => continue
+        };
+        for (&c, count) in entry.keys.iter() {
+            if let Some(placement) = geometry.locate(layout, c) {
+                fingers.entry(Item::Finger(placement.finger)).or_default().add(count);
+            }
+        }
+    }
+
+    Report {
+        chars: rank(history.key_counts().into_iter().map(|(c, count)| (Item::Char(c), count)).collect()),
+        bigrams: rank(history.bigram_counts().into_iter().map(|((a, b), count)| (Item::Bigram(a, b), count)).collect()),
+        fingers: rank(fingers.into_iter().collect()),
+        unresolved: layouts.into_iter()
+            .filter(|(_, layout)| layout.is_none())
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+}
+
+/// Score each item from its lowest likely error rate, so that a few
+/// unlucky keystrokes don't make a weakness, and from how much slower
+/// than the mean of its kind it is.
+fn rank(counts: Vec<(Item, Count)>) -> Vec<Weakness> {
+    let timed: usize = counts.iter().map(|(_, c)| c.timed).sum();
+    let mean = match timed {
+        0 => 0.0,
+        _ => seconds(counts.iter().map(|(_, c)| c.time).sum()) / timed as f64
+    };
+
+    let mut ranked: Vec<Weakness> = counts.into_iter()
+        .filter(|(_, count)| count.typed > 0)
+        .map(|(item, count)| {
+            let latency = count.latency();
+            let slowness = match latency {
+                Some(latency) if mean > 0.0 => (seconds(latency) / mean - 1.0).max(0.0),
+                _ => 0.0
+            };
+            Weakness {
+                item,
+                samples: count.typed,
+                error_rate: count.missed as f64 / count.typed as f64,
+                min_error_rate: wilson_lower_bound(count.missed, count.typed),
+                latency,
+                confidence: confidence(count.typed),
+                score: wilson_lower_bound(count.missed, count.typed) + LATENCY_WEIGHT * slowness * confidence(count.timed)
+            }
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.score.partial_cmp(&a.score).expect("scores are numbers").then(a.item.cmp(&b.item)));
+    ranked
+}
+
+fn confidence(samples: usize) -> f64 {
+    samples as f64 / (samples as f64 + HALF_CONFIDENCE_SAMPLES)
+}
+
+/// Lower bound of the Wilson score interval of a ratio.
+fn wilson_lower_bound(positive: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    let n = total as f64;
+    let p = positive as f64 / n;
+    let z2 = Z * Z;
+    let center = p + z2 / (2.0 * n);
+    let margin = Z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+    ((center - margin) / (1.0 + z2 / n)).max(0.0)
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) / 1e9
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::history::Entry;
+    use app::record::{Keystroke, Kind, Record};
+
+    // each word is typed with a keystroke every 200 ms, and the chars
+    // in `slow` take a second
+    fn session(layout: &str, words: &[&str], typed: &[&str], slow: &str) -> Entry {
+        let mut record = Record::default();
+        let mut at = Duration::from_secs(0);
+        for (word, (expected, typed)) in words.iter().zip(typed.iter()).enumerate() {
+            for (offset, (expected, typed)) in expected.chars().zip(typed.chars()).enumerate() {
+                at += match slow.contains(expected) {
+                    true => Duration::from_secs(1),
+                    false => Duration::from_millis(200)
+                };
+                record.push(Keystroke { at, kind: Kind::Char, expected, typed, word, offset });
+                if expected != typed {
+                    at += Duration::from_millis(200);
+                    record.push(Keystroke { at, kind: Kind::Char, expected, typed: expected, word, offset });
+                }
+            }
+        }
+        Entry::new(0, layout, "test", &record)
+    }
+
+    fn ansi() -> Geometry {
+        Geometry::builtin("ansi-104").unwrap()
+    }
+
+    #[test]
+    fn wilson_needs_samples() {
+        assert_eq!(wilson_lower_bound(0, 0), 0.0);
+        assert!(wilson_lower_bound(1, 2) < wilson_lower_bound(50, 100));
+        assert!(wilson_lower_bound(50, 100) < 0.5);
+        assert!(confidence(1) < confidence(100));
+    }
+
+    #[test]
+    fn rank_missed_chars_first() {
+        let words = ["sea"; 20];
+        let mut typed = vec!["sea"; 20];
+        for t in typed.iter_mut().take(8) {
+            *t = "sxa";
+        }
+        let history = History { entries: vec![session("qwerty-us", &words, &typed, "")] };
+
+        let report = analyze(&history, &ansi(), &Layout::builtin);
+        assert_eq!(report.chars[0].item, Item::Char('e'));
+        assert_eq!(report.chars[0].samples, 28);
+        assert!(report.chars[0].min_error_rate > 0.1 && report.chars[0].min_error_rate < 8.0 / 28.0);
+        assert_eq!(report.bigrams[0].item, Item::Bigram('s', 'e'));
+        assert_eq!(report.fingers[0].item, Item::Finger(Finger::LeftMiddle));
+    }
+
+    #[test]
+    fn rank_slow_chars_first() {
+        let words = ["sea"; 20];
+        let history = History { entries: vec![session("qwerty-us", &words, &words, "a")] };
+
+        let report = analyze(&history, &ansi(), &Layout::builtin);
+        assert_eq!(report.chars[0].item, Item::Char('a'));
+        assert_eq!(report.chars[0].latency, Some(Duration::from_secs(1)));
+        assert_eq!(report.chars[0].error_rate, 0.0);
+        assert_eq!(report.bigrams[0].item, Item::Bigram('e', 'a'));
+    }
+
+    #[test]
+    fn fingers_follow_the_layout() {
+        // `e` is on the left middle finger in qwerty, on the left index in bépo
+        let words = ["e"; 20];
+        let typed = ["x"; 20];
+        let history = History { entries: vec![
+            session("qwerty-us", &words, &typed, ""),
+            session("bepo", &words, &typed, ""),
+            session("gone.xkb", &words, &typed, "")
+        ] };
+
+        let report = analyze(&history, &ansi(), &Layout::builtin);
+        let fingers: Vec<(Item, usize)> = report.fingers.iter().map(|w| (w.item, w.samples)).collect();
+        assert_eq!(fingers, vec![(Item::Finger(Finger::LeftMiddle), 40), (Item::Finger(Finger::LeftIndex), 40)]);
+        assert_eq!(report.unresolved, vec!["gone.xkb".to_string()]);
+        assert_eq!(format!("{}", Item::Bigram(' ', 'a')), "\u{2423}a");
+    }
+
+    #[test]
+    fn fingers_follow_the_geometry() {
+        // `ê` is under the left pinky, but for the middle column of a TypeMatrix
+        let words = ["ê"; 20];
+        let history = History { entries: vec![session("bepo", &words, &words, "")] };
+
+        let finger = |geometry: &str| analyze(&history, &Geometry::builtin(geometry).unwrap(), &Layout::builtin)
+            .fingers[0].item;
+        assert_eq!(finger("iso-105"), Item::Finger(Finger::LeftPinky));
+        assert_eq!(finger("typematrix"), Item::Finger(Finger::LeftIndex));
+    }
+}
@@ -0,0 +1,231 @@
+use std::error;
+use std::fmt;
+use std::path::PathBuf;
+
+use app::config::{self, Config};
+
+
+pub static USAGE: &str = "\
+Usage: typing_trainer [train] [options] [words file]
+       typing_trainer lesson [options] [layout]
+       typing_trainer stats
+       typing_trainer layouts list
+
+Commands:
+  train           type words of a file, or bundled words (default)
+  lesson          type the current stage of the lessons of a layout
+  stats           show the weakest characters, bigrams and fingers
+  layouts list    show the builtin layouts
+
+Options of train and lesson, overriding the config file:
+  --words <file>           word list, the bundled words otherwise
+  --language <code>        language of the words of the database
+  --database <file>        words database, the bundled one otherwise
+  --count <n>              words of a session
+  --layout <name>          keyboard layout, or XKB symbols file as path/to/fr(bepo)
+  --geometry <name>        physical keyboard
+  --feedback <mode>        background, overtype, caret, blind or flash
+  --theme <preset>         colors preset, instead of the theme file
+  --error-policy <name>    block, forward or correctable
+  --word-policy <name>     restart, lock or undo
+  --align <side>           center, top, top-left, bottom-right...
+  --gap <n>                cells between the words and their frame
+  --margin <n>             cells between the frame and the terminal sides
+
+Options of all commands:
+  --config <file>          config file, instead of the default one
+  -h, --help               show this help
+";
+
+
+//---
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    // option not known by the command
+    UnknownOption(String),
+    // option given last, without its value
+    MissingValue(String),
+    // argument left once the command had all it takes
+    Unexpected(String),
+    // option, its value, and what was expected instead
+    Invalid(String, String, String)
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CliError::UnknownOption(ref option) => write!(f, "unknown option {}, see --help", option),
+            CliError::MissingValue(ref option) => write!(f, "missing value after {}", option),
+            CliError::Unexpected(ref arg) => write!(f, "unexpected argument {}, see --help", arg),
+            CliError::Invalid(ref option, ref value, ref expected) =>
+                write!(f, "invalid {}: {}, expected {}", option, value, expected)
+        }
+    }
+}
+
+impl error::Error for CliError {}
+
+
+//---
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Train,
+    Lesson,
+    Stats,
+    Layouts,
+    Help
+}
+
+/// Arguments of the command line, the options being applied on top of the
+/// config file.
+#[derive(Debug, PartialEq)]
+pub struct Cli {
+    pub command: Command,
+    // config file given instead of the default one
+    pub config: Option<PathBuf>,
+    // options as given, config keys and values, in the order given
+    options: Vec<(String, &'static str, String)>
+}
+
+/// Read the arguments, without the program name. Train is the command
+/// when none is given.
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Cli, CliError> {
+    let mut args = args.into_iter().peekable();
+    let command = match args.peek().map(String::as_str) {
+        Some("train") => Some(Command::Train),
+        Some("lesson") => Some(Command::Lesson),
+        Some("stats") => Some(Command::Stats),
+        Some("layouts") => Some(Command::Layouts),
+        Some("help") => Some(Command::Help),
+        // no command, but the arguments of train
+        _ => None
+    };
+    if command.is_some() {
+        args.next();
+    }
+    let command = command.unwrap_or(Command::Train);
+    let mut cli = Cli { command, config: None, options: Vec::new() };
+    if command == Command::Layouts && args.peek().map(String::as_str) == Some("list") {
+        args.next();
+    }
+
+    // the key of the positional argument, if the command takes one
+    let mut positional = match command {
+        Command::Train => Some("words"),
+        Command::Lesson => Some("layout"),
+        Command::Stats | Command::Layouts | Command::Help => None
+    };
+
+    while let Some(arg) = args.next() {
+        // the value of an option is either joined by '=' or the next argument
+        let (option, joined) = match arg.find('=') {
+            Some(i) if arg.starts_with("--") => (arg[..i].to_string(), Some(arg[i + 1..].to_string())),
+            _ => (arg.clone(), None)
+        };
+        let takes_options = command == Command::Train || command == Command::Lesson;
+        let key = match option.as_str() {
+            "-h" | "--help" => {
+                cli.command = Command::Help;
+                continue;
+            }
+            "--config" => None,
+            _ if option.starts_with("--") => match config::KEYS.iter().find(|&&key| key == &option[2..]) {
+                Some(&key) if takes_options => Some(key),
+                _ => return Err(CliError::UnknownOption(option))
+            },
+            _ if option.starts_with('-') && option.len() > 1 => return Err(CliError::UnknownOption(option)),
+            _ => match positional.take() {
+                Some(key) => {
+                    cli.options.push((key.to_string(), key, arg));
+                    continue;
+                }
+                None => return Err(CliError::Unexpected(arg))
+            }
+        };
+
+        let value = match joined.or_else(|| args.next()) {
+            Some(value) => value,
+            None => return Err(CliError::MissingValue(option))
+        };
+        match key {
+            Some(key) => cli.options.push((option, key, value)),
+            None => cli.config = Some(PathBuf::from(value))
+        }
+    }
+
+    Ok(cli)
+}
+
+impl Cli {
+    /// Change the config by the options, the last one of a key winning.
+    pub fn apply(&self, config: &mut Config) -> Result<(), CliError> {
+        for &(ref option, key, ref value) in self.options.iter() {
+            config.set(key, value)
+                .map_err(|expected| CliError::Invalid(option.clone(), value.clone(), expected))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::exercise::ErrorPolicy;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    fn config_of(line: &str) -> Result<Config, CliError> {
+        let mut config = Config::default();
+        parse(args(line))?.apply(&mut config)?;
+        Ok(config)
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(parse(args("")).unwrap().command, Command::Train);
+        assert_eq!(parse(args("words.txt")).unwrap().command, Command::Train);
+        assert_eq!(parse(args("lesson bepo")).unwrap().command, Command::Lesson);
+        assert_eq!(parse(args("stats")).unwrap().command, Command::Stats);
+        assert_eq!(parse(args("layouts list")).unwrap().command, Command::Layouts);
+        assert_eq!(parse(args("layouts")).unwrap().command, Command::Layouts);
+        assert_eq!(parse(args("lesson --help")).unwrap().command, Command::Help);
+
+        let cli = parse(args("stats --config other.toml")).unwrap();
+        assert_eq!(cli.config, Some(PathBuf::from("other.toml")));
+    }
+
+    #[test]
+    fn options_override_config() {
+        let config = config_of("train --gap 1 --error-policy=forward words.txt --gap 0").unwrap();
+        assert_eq!(config.gap, 0);
+        assert_eq!(config.error_policy, ErrorPolicy::Forward);
+        assert_eq!(config.words, Some(PathBuf::from("words.txt")));
+
+        let config = config_of("--database=fr.words").unwrap();
+        assert_eq!(config.database, Some(PathBuf::from("fr.words")));
+
+        let config = config_of("lesson bepo --feedback caret").unwrap();
+        assert_eq!(config.layout, "bepo");
+        assert_eq!(config.feedback, "caret");
+        assert_eq!(config.words, None);
+    }
+
+    #[test]
+    fn readable_errors() {
+        let error = |line: &str| config_of(line).unwrap_err().to_string();
+
+        assert_eq!(error("--colour red"), "unknown option --colour, see --help");
+        assert_eq!(error("stats --gap 1"), "unknown option --gap, see --help");
+        assert_eq!(error("train -x"), "unknown option -x, see --help");
+        assert_eq!(error("train --count"), "missing value after --count");
+        assert_eq!(error("train a.txt b.txt"), "unexpected argument b.txt, see --help");
+        assert_eq!(error("layouts list all"), "unexpected argument all, see --help");
+        assert_eq!(error("--count=many"), "invalid --count: many, expected a number from 1 to 1000");
+        assert_eq!(error("--align middle"),
+                   "invalid --align: middle, expected one of top-left, top, top-right, left, center, right, \
+                    bottom-left, bottom, bottom-right");
+        assert!(error("lesson dvorak-xx").starts_with("invalid layout: dvorak-xx, expected one of "));
+    }
+}
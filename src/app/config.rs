@@ -0,0 +1,285 @@
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use toml;
+
+use app::exercise::ErrorPolicy;
+use app::geometry::Geometry;
+use app::layout::Layout;
+use app::layout::xkb;
+use app::session::WordPolicy;
+use app::ui::Alignment;
+use app::ui::feedback;
+use app::ui::theme::Theme;
+use app::word::db;
+
+
+// keys of the config file, which are also the options of the command line
+pub static KEYS: [&str; 13] = [
+    "words", "language", "database", "count", "layout", "geometry", "feedback",
+    "theme", "error-policy", "word-policy", "align", "gap", "margin"
+];
+
+static ERROR_POLICIES: [(ErrorPolicy, &str); 3] = [
+    (ErrorPolicy::Block, "block"),
+    (ErrorPolicy::Forward, "forward"),
+    (ErrorPolicy::Correctable, "correctable")
+];
+
+static WORD_POLICIES: [(WordPolicy, &str); 3] = [
+    (WordPolicy::Restart, "restart"),
+    (WordPolicy::Lock, "lock"),
+    (WordPolicy::Undo, "undo")
+];
+
+// most words of a session, and widest frame and margins, far beyond any
+// terminal anyway
+static MAX_COUNT: usize = 1000;
+static MAX_SPACING: u16 = 20;
+
+
+//---
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    // message of the toml parser
+    Syntax(String),
+    // key of the file, the value which cannot be understood, and what was
+    // expected instead
+    Invalid(String, String, String)
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigError::Io(ref err) => write!(f, "cannot access config: {}", err),
+            ConfigError::Syntax(ref message) => write!(f, "config is malformed: {}", message),
+            ConfigError::Invalid(ref key, ref value, ref expected) =>
+                write!(f, "config has an invalid {}: {}, expected {}", key, value, expected)
+        }
+    }
+}
+
+impl error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ConfigError::Io(ref err) => Some(err),
+            ConfigError::Syntax(_) | ConfigError::Invalid(_, _, _) => None
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(err: io::Error) -> ConfigError {
+        ConfigError::Io(err)
+    }
+}
+
+
+//---
+/// Settings of the sessions, from the config file and then the command line.
+#[derive(Debug, Clone)]
+pub struct Config {
+    // word list to train on, the bundled words otherwise
+    pub words: Option<PathBuf>,
+    // language of the bundled words
+    pub language: String,
+    // words database, the bundled one otherwise
+    pub database: Option<PathBuf>,
+    // words of a session
+    pub count: usize,
+    // builtin layout, or XKB symbols file with an optional variant
+    pub layout: String,
+    pub geometry: String,
+    pub feedback: String,
+    // preset used instead of the theme file
+    pub theme: Option<String>,
+    pub error_policy: ErrorPolicy,
+    pub word_policy: WordPolicy,
+    pub align: Alignment,
+    // cells between the words and their frame
+    pub gap: u16,
+    // cells between the frame and the sides of the terminal
+    pub margin: u16
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            words: None,
+            language: "en".to_string(),
+            database: None,
+            count: 20,
+            layout: "qwerty-us".to_string(),
+            geometry: "ansi-104".to_string(),
+            feedback: "background".to_string(),
+            theme: None,
+            error_policy: ErrorPolicy::Block,
+            word_policy: WordPolicy::Lock,
+            align: Alignment::centered(),
+            gap: 2,
+            margin: 1
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/typing_trainer`, the config home defaulting to
+/// `~/.config`.
+pub fn config_dir() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_home.join("typing_trainer"))
+}
+
+/// `config.toml` in the config directory.
+pub fn default_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("config.toml"))
+}
+
+impl Config {
+    /// Load a config file, a missing file giving the defaults.
+    pub fn load(path: &Path) -> Result<Config, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Config::read(&text),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(err) => Err(ConfigError::Io(err))
+        }
+    }
+
+    /// Read a config: a value for any of the `KEYS`, strings or numbers as
+    /// on the command line, the others keeping their defaults.
+    pub fn read(text: &str) -> Result<Config, ConfigError> {
+        let table = match text.parse::<toml::Value>() {
+            Ok(toml::Value::Table(table)) => table,
+            Ok(value) => return Err(ConfigError::Syntax(format!("not a table: {}", value))),
+            Err(err) => return Err(ConfigError::Syntax(err.to_string()))
+        };
+
+        let mut config = Config::default();
+        for (key, value) in table.iter() {
+            let text = match *value {
+                toml::Value::String(ref text) => Some(text.clone()),
+                toml::Value::Integer(number) => Some(number.to_string()),
+                _ => None
+            };
+            let invalid = |expected: String| ConfigError::Invalid(key.clone(), value.to_string(), expected);
+            match (KEYS.contains(&key.as_str()), text) {
+                (false, _) => return Err(ConfigError::Invalid("key".to_string(), key.clone(), KEYS.join(", "))),
+                (true, Some(text)) => config.set(key, &text).map_err(invalid)?,
+                (true, None) => return Err(invalid(Config::expected(key)))
+            }
+        }
+        Ok(config)
+    }
+
+    /// Change the value of a key, or tell what was expected instead.
+    pub fn set(&mut self, key: &str, text: &str) -> Result<(), String> {
+        let invalid = || Err(Config::expected(key));
+        match key {
+            "words" if !text.is_empty() => self.words = Some(PathBuf::from(text)),
+            "language" if !text.is_empty() => self.language = text.to_string(),
+            "database" if !text.is_empty() => self.database = Some(PathBuf::from(text)),
+            "count" => match text.parse() {
+                Ok(count) if count > 0 && count <= MAX_COUNT => self.count = count,
+                _ => return invalid()
+            },
+            "layout" if Layout::builtin(text).is_some() || xkb::split_variant(text).0.is_file() =>
+                self.layout = text.to_string(),
+            "geometry" if Geometry::builtin(text).is_some() => self.geometry = text.to_string(),
+            "feedback" if feedback::new(text).is_some() => self.feedback = text.to_string(),
+            "theme" if Theme::preset(text).is_some() => self.theme = Some(text.to_string()),
+            "error-policy" => match ERROR_POLICIES.iter().find(|&&(_, name)| name == text) {
+                Some(&(policy, _)) => self.error_policy = policy,
+                None => return invalid()
+            },
+            "word-policy" => match WORD_POLICIES.iter().find(|&&(_, name)| name == text) {
+                Some(&(policy, _)) => self.word_policy = policy,
+                None => return invalid()
+            },
+            "align" => match Alignment::parse(text) {
+                Some(align) => self.align = align,
+                None => return invalid()
+            },
+            "gap" | "margin" => match text.parse() {
+                Ok(spacing) if spacing <= MAX_SPACING && key == "gap" => self.gap = spacing,
+                Ok(spacing) if spacing <= MAX_SPACING => self.margin = spacing,
+                _ => return invalid()
+            },
+            _ => return invalid()
+        }
+        Ok(())
+    }
+
+    /// What a key accepts, to tell after an invalid value.
+    pub fn expected(key: &str) -> String {
+        let names = |names: &[&str]| format!("one of {}", names.join(", "));
+        match key {
+            "words" => "the path of a word list".to_string(),
+            "language" => format!("a language code of the words database, the bundled one having {}",
+                                  db::LANGUAGES.join(", ")),
+            "database" => "the path of a words database".to_string(),
+            "count" => format!("a number from 1 to {}", MAX_COUNT),
+            "layout" => format!("{}, or an XKB symbols file as path/to/fr(bepo)", names(&Layout::builtin_names())),
+            "geometry" => names(&Geometry::builtin_names()),
+            "feedback" => names(feedback::names()),
+            "theme" => names(Theme::preset_names()),
+            "error-policy" => names(&ERROR_POLICIES.iter().map(|&(_, name)| name).collect::<Vec<_>>()),
+            "word-policy" => names(&WORD_POLICIES.iter().map(|&(_, name)| name).collect::<Vec<_>>()),
+            "align" => names(Alignment::names()),
+            "gap" | "margin" => format!("a number from 0 to {}", MAX_SPACING),
+            _ => names(&KEYS)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_config_file() {
+        let text = "count = 30\nlayout = \"bepo\"\nfeedback = \"blind\"\n\
+                    error-policy = \"correctable\"\nalign = \"top-left\"\ngap = \"1\"\n";
+        let config = Config::read(text).expect("reading failed");
+
+        assert_eq!(config.count, 30);
+        assert_eq!(config.layout, "bepo");
+        assert_eq!(config.feedback, "blind");
+        assert_eq!(config.error_policy, ErrorPolicy::Correctable);
+        assert_eq!(config.gap, 1);
+        // the rest is kept
+        assert_eq!(config.word_policy, WordPolicy::Lock);
+        assert_eq!(config.margin, 1);
+        assert_eq!(config.words, None);
+        assert_eq!(config.database, None);
+    }
+
+    #[test]
+    fn invalid_values() {
+        let error = |text: &str| Config::read(text).unwrap_err().to_string();
+
+        assert_eq!(error("count = 0"), "config has an invalid count: 0, expected a number from 1 to 1000");
+        assert_eq!(error("gap = \"wide\""), "config has an invalid gap: \"wide\", expected a number from 0 to 20");
+        assert_eq!(error("margin = -1"), "config has an invalid margin: -1, expected a number from 0 to 20");
+        assert_eq!(error("word-policy = true"),
+                   "config has an invalid word-policy: true, expected one of restart, lock, undo");
+        assert!(error("layout = \"dvorak-xx\"").starts_with("config has an invalid layout: \"dvorak-xx\", expected one of "));
+        assert!(error("colour = \"red\"").starts_with("config has an invalid key: colour, expected words, language"));
+        assert!(error("count = ").starts_with("config is malformed"));
+
+        let mut config = Config::default();
+        assert_eq!(config.set("theme", "neon"), Err("one of default, colorblind, tritan, monochrome".to_string()));
+        assert_eq!(config.set("theme", "tritan"), Ok(()));
+        assert_eq!(config.theme, Some("tritan".to_string()));
+        // languages are those of the database, only known once loaded
+        assert_eq!(config.set("language", "de"), Ok(()));
+        assert_eq!(config.set("language", ""),
+                   Err("a language code of the words database, the bundled one having en, fr".to_string()));
+    }
+}
@@ -0,0 +1,327 @@
+use std::time::{Duration, Instant};
+
+use app::word::Bucket;
+use app::exercise::{ErrorPolicy, Stroke};
+use app::layout::compose::{self, DeadKeys};
+use app::record::{Keystroke, Kind, Record};
+use app::session::{self, Session, Transition, WordPolicy};
+
+
+
+pub fn new(bucket: &Bucket, error_policy: ErrorPolicy, word_policy: WordPolicy) -> Engine {
+    Engine {
+        session: session::new(bucket, error_policy, word_policy),
+        aborted: false,
+        started: Instant::now(),
+        record: Record::default(),
+        dead_keys: DeadKeys::default(),
+        composing: None
+    }
+}
+
+
+//---
+/// Key event, independent of the terminal library.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Input {
+    Char(char),
+    Backspace,
+    Escape
+}
+
+
+//---
+/// State change produced by a key event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Accepted { word: usize, offset: usize },
+    Rejected { word: usize, offset: usize, typed: char },
+    Erased { word: usize, offset: usize },
+    // a dead key waits for the char it applies to
+    Composing { word: usize, offset: usize },
+    WordCompleted(usize),
+    WordRestarted(usize),
+    Finished,
+    Aborted
+}
+
+impl Change {
+    /// Index of the word affected by the change, if any.
+    pub fn word(&self) -> Option<usize> {
+        match *self {
+            Change::Accepted { word, .. } |
+            Change::Rejected { word, .. } |
+            Change::Erased { word, .. } |
+            Change::Composing { word, .. } |
+            Change::WordCompleted(word) |
+            Change::WordRestarted(word) => Some(word),
+            Change::Finished | Change::Aborted => None
+        }
+    }
+}
+
+
+//---
+/// Typing state machine of a whole session.
+#[derive(Debug)]
+pub struct Engine {
+    session: Session,
+    aborted: bool,
+    started: Instant,
+    record: Record,
+    dead_keys: DeadKeys,
+    // mark of the dead key typed last
+    composing: Option<char>
+}
+
+impl Engine {
+    /// Combine the chars typed after a dead key, for the terminals which
+    /// send the dead key on its own.
+    pub fn with_dead_keys(mut self, dead_keys: DeadKeys) -> Engine {
+        self.dead_keys = dead_keys;
+        self
+    }
+
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Keystrokes typed so far.
+    pub fn record(&self) -> &Record {
+        &self.record
+    }
+
+    /// Mark of the dead key waiting for the next char.
+    pub fn composing(&self) -> Option<char> {
+        self.composing
+    }
+
+    /// Next char to type, or the char to type after the dead key when
+    /// the expected one is composed from it.
+    pub fn next_char(&self) -> Option<char> {
+        let (word, _) = self.cursor()?;
+        let expected = self.session.exercise(word).expected()?;
+        match self.composing.and_then(|mark| self.dead_keys.decompose(expected).filter(|&(m, _)| m == mark)) {
+            Some((_, base)) => Some(base),
+            None => Some(expected)
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.aborted || self.session.is_finished()
+    }
+
+    /// Word and offset of the next character to type.
+    pub fn cursor(&self) -> Option<(usize, usize)> {
+        if self.is_over() {
+            return None;
+        }
+        let word = self.session.current();
+        Some((word, self.session.exercise(word).progress()))
+    }
+
+    /// Time since the start of the session.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    pub fn feed(&mut self, input: Input) -> Vec<Change> {
+        let at = self.elapsed();
+        self.feed_at(input, at)
+    }
+
+    /// Feed an input which happened `at` that time of the session.
+    pub fn feed_at(&mut self, input: Input, at: Duration) -> Vec<Change> {
+        let (word, offset) = match self.cursor() {
+            Some(cursor) => cursor,
+            None => return Vec::new()
+        };
+
+        let mut changes = Vec::new();
+        match input {
+            Input::Char(typed) => {
+                let expected = self.session.exercise(word).expected().expect("the current word is not done");
+                // a dead key is only a dead key when its own char isn't expected
+                let typed = match (self.composing.take(), self.dead_keys.mark(typed)) {
+                    (Some(mark), _) => compose::apply(mark, typed).unwrap_or(typed),
+                    (None, Some(mark)) if typed != expected => {
+                        self.composing = Some(mark);
+                        self.record.push(Keystroke { at, kind: Kind::Dead, expected, typed, word, offset });
+                        changes.push(Change::Composing { word, offset });
+                        return changes;
+                    }
+                    (None, _) => typed
+                };
+                let (stroke, transition) = self.session.type_char(typed);
+                match stroke {
+                    Stroke::Accepted => changes.push(Change::Accepted { word, offset }),
+                    Stroke::Rejected => changes.push(Change::Rejected { word, offset, typed }),
+                    Stroke::Ignored => ()
+                }
+                if stroke != Stroke::Ignored {
+                    self.record.push(Keystroke { at, kind: Kind::Char, expected, typed, word, offset });
+                }
+                match transition {
+                    Transition::Stay => (),
+                    Transition::Restarted => changes.push(Change::WordRestarted(word)),
+                    Transition::Advanced => changes.push(Change::WordCompleted(word))
+                }
+                if self.session.is_finished() {
+                    changes.push(Change::Finished);
+                }
+            }
+            Input::Backspace => {
+                let erased = self.composing.take().is_some() || self.session.backspace();
+                // recorded where the cursor is left, even when nothing was erased
+                let (word, offset) = self.cursor().expect("backspace cannot end the session");
+                if erased {
+                    changes.push(Change::Erased { word, offset });
+                }
+                let expected = self.session.exercise(word).expected().expect("the current word is not done");
+                self.record.push(Keystroke { at, kind: Kind::Backspace, expected, typed: '\u{8}', word, offset });
+            }
+            Input::Escape => {
+                self.aborted = true;
+                changes.push(Change::Aborted);
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn script(engine: &mut Engine, keys: &str) -> Vec<Change> {
+        keys.chars()
+            .map(|c| match c {
+                '\u{8}' => Input::Backspace,
+                '\u{1b}' => Input::Escape,
+                c => Input::Char(c)
+            })
+            .flat_map(|input| engine.feed(input))
+            .collect()
+    }
+
+    #[test]
+    fn complete_session() {
+        let mut engine = new(&Bucket::new(vec!["ab", "c"]), ErrorPolicy::Block, WordPolicy::Lock);
+
+        assert_eq!(script(&mut engine, "axbc"), vec![
+            Change::Accepted { word: 0, offset: 0 },
+            Change::Rejected { word: 0, offset: 1, typed: 'x' },
+            Change::Accepted { word: 0, offset: 1 },
+            Change::WordCompleted(0),
+            Change::Accepted { word: 1, offset: 0 },
+            Change::WordCompleted(1),
+            Change::Finished
+        ]);
+        assert!(engine.is_over());
+        assert_eq!(engine.cursor(), None);
+        assert!(engine.feed(Input::Char('d')).is_empty());
+    }
+
+    #[test]
+    fn abort_session() {
+        let mut engine = new(&Bucket::new(vec!["ab"]), ErrorPolicy::Block, WordPolicy::Lock);
+
+        assert_eq!(script(&mut engine, "a\u{1b}b"), vec![
+            Change::Accepted { word: 0, offset: 0 },
+            Change::Aborted
+        ]);
+        assert!(engine.is_over());
+    }
+
+    #[test]
+    fn erase_across_words() {
+        let mut engine = new(&Bucket::new(vec!["ab", "c"]), ErrorPolicy::Correctable, WordPolicy::Undo);
+
+        assert_eq!(script(&mut engine, "ab\u{8}\u{8}"), vec![
+            Change::Accepted { word: 0, offset: 0 },
+            Change::Accepted { word: 0, offset: 1 },
+            Change::WordCompleted(0),
+            Change::Erased { word: 0, offset: 1 },
+            Change::Erased { word: 0, offset: 0 }
+        ]);
+        assert_eq!(engine.cursor(), Some((0, 0)));
+    }
+
+    #[test]
+    fn restart_word() {
+        let mut engine = new(&Bucket::new(vec!["ab"]), ErrorPolicy::Forward, WordPolicy::Restart);
+
+        assert_eq!(script(&mut engine, "xb"), vec![
+            Change::Rejected { word: 0, offset: 0, typed: 'x' },
+            Change::Accepted { word: 0, offset: 1 },
+            Change::WordRestarted(0)
+        ]);
+        assert_eq!(engine.cursor(), Some((0, 0)));
+    }
+
+    #[test]
+    fn record_keystrokes() {
+        let mut engine = new(&Bucket::new(vec!["ab", "c"]), ErrorPolicy::Block, WordPolicy::Lock);
+
+        for &(ms, c) in [(0, 'a'), (150, 'x'), (300, 'b'), (320, '\u{8}'), (500, 'c'), (600, 'd')].iter() {
+            let input = match c {
+                '\u{8}' => Input::Backspace,
+                c => Input::Char(c)
+            };
+            engine.feed_at(input, Duration::from_millis(ms));
+        }
+
+        let typed: Vec<(u64, Kind, char, char, usize, usize)> = engine.record().keystrokes().iter()
+            .map(|k| (k.at.as_millis() as u64, k.kind, k.expected, k.typed, k.word, k.offset))
+            .collect();
+        assert_eq!(typed, vec![
+            (0, Kind::Char, 'a', 'a', 0, 0),
+            (150, Kind::Char, 'b', 'x', 0, 1),
+            (300, Kind::Char, 'b', 'b', 0, 1),
+            // nothing to erase in a locked word
+            (320, Kind::Backspace, 'c', '\u{8}', 1, 0),
+            (500, Kind::Char, 'c', 'c', 1, 0)
+        ]);
+    }
+
+    #[test]
+    fn compose_after_dead_key() {
+        let dead_keys = DeadKeys::new(vec!['\u{302}', '\u{308}']);
+        let mut engine = new(&Bucket::new(vec!["fête", "^ë"]), ErrorPolicy::Block, WordPolicy::Lock)
+            .with_dead_keys(dead_keys);
+
+        // combining mark, then spacing form of the dead key
+        assert_eq!(script(&mut engine, "f\u{302}"), vec![
+            Change::Accepted { word: 0, offset: 0 },
+            Change::Composing { word: 0, offset: 1 }
+        ]);
+        assert_eq!(engine.composing(), Some('\u{302}'));
+        assert_eq!(engine.next_char(), Some('e'));
+        assert_eq!(script(&mut engine, "e"), vec![Change::Accepted { word: 0, offset: 1 }]);
+        assert_eq!(engine.next_char(), Some('t'));
+
+        // cancelled by backspace, not counted as a mistake
+        assert_eq!(script(&mut engine, "t^\u{8}^e"), vec![
+            Change::Accepted { word: 0, offset: 2 },
+            Change::Composing { word: 0, offset: 3 },
+            Change::Erased { word: 0, offset: 3 },
+            Change::Composing { word: 0, offset: 3 },
+            Change::Rejected { word: 0, offset: 3, typed: 'ê' }
+        ]);
+        script(&mut engine, "e");
+
+        // the spacing form is expected as itself, the composed one as usual
+        assert_eq!(script(&mut engine, "^ë"), vec![
+            Change::Accepted { word: 1, offset: 0 },
+            Change::Accepted { word: 1, offset: 1 },
+            Change::WordCompleted(1),
+            Change::Finished
+        ]);
+
+        let typed: Vec<(char, char)> = engine.record().chars().iter().map(|k| (k.expected, k.typed)).collect();
+        assert_eq!(typed, vec![('f', 'f'), ('ê', 'ê'), ('t', 't'), ('e', 'ê'), ('e', 'e'), ('^', '^'), ('ë', 'ë')]);
+        let kinds: Vec<Kind> = engine.record().keystrokes().iter().map(|k| k.kind).collect();
+        assert_eq!(kinds, vec![Kind::Char, Kind::Dead, Kind::Char, Kind::Char, Kind::Dead, Kind::Backspace,
+                               Kind::Dead, Kind::Char, Kind::Char, Kind::Char, Kind::Char]);
+    }
+}
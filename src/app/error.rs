@@ -0,0 +1,137 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use libc;
+
+use app::cli::CliError;
+use app::config::{Config, ConfigError};
+use app::history::HistoryError;
+use app::lesson::ProgressError;
+use app::layout::xkb::XkbError;
+use app::ui::theme::ThemeError;
+use app::word::LoadError;
+
+
+//---
+/// Whatever stops the application, told to the user once the terminal is
+/// restored.
+#[derive(Debug)]
+pub enum AppError {
+    Usage(CliError),
+    // file given by the command line, which isn't there
+    MissingConfig(PathBuf),
+    Config(PathBuf, ConfigError),
+    // key and value of a config which was not checked by `Config::set`
+    InvalidConfig(String, String),
+    Theme(PathBuf, ThemeError),
+    // XKB symbols file of the layout
+    Layout(PathBuf, XkbError),
+    History(PathBuf, HistoryError),
+    // current stages of the lessons
+    Progress(PathBuf, ProgressError),
+    Words(PathBuf, LoadError),
+    // language of the config, and the ones of the database
+    Language(String, Vec<String>),
+    // the words don't fit in the terminal, at the start of the session
+    TooSmall,
+    // size, settings or output of the terminal
+    Terminal(io::Error),
+    // signal number
+    Interrupted(i32),
+    // message and location of the panic
+    Panic(String)
+}
+
+impl AppError {
+    /// Status of the process, as shells expect it after a signal.
+    pub fn exit_code(&self) -> i32 {
+        match *self {
+            AppError::Interrupted(signal) => 128 + signal,
+            AppError::Panic(_) => 101,
+            _ => 1
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AppError::Usage(ref err) => write!(f, "{}", err),
+            AppError::MissingConfig(ref path) => write!(f, "{}: no such config file", path.display()),
+            AppError::Config(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            AppError::InvalidConfig(ref key, ref value) =>
+                write!(f, "invalid {}: {}, expected {}", key, value, Config::expected(key)),
+            AppError::Theme(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            AppError::Layout(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            AppError::History(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            AppError::Progress(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            AppError::Words(ref path, ref err) => write!(f, "{}: {}", path.display(), err),
+            AppError::Language(ref language, ref languages) =>
+                write!(f, "no words in language {}, the database has {}", language, languages.join(", ")),
+            AppError::TooSmall => write!(f, "the words do not fit in the terminal, within the margins and the frame"),
+            AppError::Terminal(ref err) => write!(f, "cannot use the terminal: {}", err),
+            AppError::Interrupted(libc::SIGINT) => write!(f, "interrupted"),
+            AppError::Interrupted(libc::SIGTERM) => write!(f, "terminated"),
+            AppError::Interrupted(signal) => write!(f, "stopped by signal {}", signal),
+            AppError::Panic(ref message) => write!(f, "unexpected failure, please report it: {}", message)
+        }
+    }
+}
+
+impl error::Error for AppError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            AppError::Usage(ref err) => Some(err),
+            AppError::Config(_, ref err) => Some(err),
+            AppError::Theme(_, ref err) => Some(err),
+            AppError::Layout(_, ref err) => Some(err),
+            AppError::History(_, ref err) => Some(err),
+            AppError::Progress(_, ref err) => Some(err),
+            AppError::Words(_, ref err) => Some(err),
+            AppError::Terminal(ref err) => Some(err),
+            AppError::MissingConfig(_) | AppError::InvalidConfig(_, _) | AppError::Language(_, _) | AppError::TooSmall | AppError::Interrupted(_) | AppError::Panic(_) => None
+        }
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(err: io::Error) -> AppError {
+        AppError::Terminal(err)
+    }
+}
+
+impl From<CliError> for AppError {
+    fn from(err: CliError) -> AppError {
+        AppError::Usage(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn readable_messages() {
+        let err = AppError::Config(PathBuf::from("config.toml"), ConfigError::Syntax("expected a value".to_string()));
+        assert_eq!(err.to_string(), "config.toml: config is malformed: expected a value");
+        assert_eq!(err.exit_code(), 1);
+
+        let err = AppError::InvalidConfig("geometry".to_string(), "round".to_string());
+        assert!(err.to_string().starts_with("invalid geometry: round, expected one of iso-105, "));
+
+        let err = AppError::Progress(PathBuf::from("lessons"), ProgressError::Malformed(2));
+        assert_eq!(err.to_string(), "lessons: lessons progress is malformed at line 2");
+
+        let err = AppError::Language("de".to_string(), vec!["en".to_string(), "fr".to_string()]);
+        assert_eq!(err.to_string(), "no words in language de, the database has en, fr");
+
+        let err = AppError::from(io::Error::other("not a terminal"));
+        assert_eq!(err.to_string(), "cannot use the terminal: not a terminal");
+
+        assert_eq!(AppError::Interrupted(libc::SIGINT).to_string(), "interrupted");
+        assert_eq!(AppError::Interrupted(libc::SIGTERM).exit_code(), 128 + 15);
+        assert_eq!(AppError::Panic("oops (src/main.rs:1:1)".to_string()).exit_code(), 101);
+    }
+}
@@ -1,28 +1,310 @@
-
 use std::ops::Index;
 
-use app::word::Word;
+use app::word::{self, Word};
 
 
 
+#[allow(dead_code)]
 pub fn new(word: &Word) -> Exercise {
-    Exercise(word.raw.chars().collect())
+    with_policy(word, ErrorPolicy::Block)
+}
+
+pub fn with_policy(word: &Word, policy: ErrorPolicy) -> Exercise {
+    let graphemes: Vec<String> = word.graphemes().into_iter().map(String::from).collect();
+    let states = vec![Typing::Pending; graphemes.len()];
+    let mistaken = vec![false; graphemes.len()];
+    Exercise {
+        graphemes,
+        states,
+        mistaken,
+        progress: 0,
+        partial: 0,
+        policy
+    }
 }
 
 
 //---
-pub struct Exercise(Vec<char>);
+/// How the exercise reacts to a mistyped character.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorPolicy {
+    /// Wait on the mistyped character until the right key is typed.
+    Block,
+    /// Mark the character as failed, move forward and forbid correction.
+    Forward,
+    /// Mark the character as failed, move forward and allow backspace.
+    Correctable
+}
+
+impl ErrorPolicy {
+    pub fn advance_on_error(&self) -> bool {
+        match *self {
+            ErrorPolicy::Block => false,
+            ErrorPolicy::Forward | ErrorPolicy::Correctable => true
+        }
+    }
+
+    pub fn allow_correction(&self) -> bool {
+        match *self {
+            ErrorPolicy::Block | ErrorPolicy::Forward => false,
+            ErrorPolicy::Correctable => true
+        }
+    }
+}
+
+
+//---
+/// State of a single character (a grapheme) of the exercise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Typing {
+    Pending,
+    Correct,
+    // the typed character
+    Incorrect(char),
+    // right at last, but after at least one mistake
+    Corrected
+}
+
+
+//---
+/// Outcome of a keystroke on the exercise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stroke {
+    Accepted,
+    Rejected,
+    // nothing left to type
+    Ignored
+}
+
+
+//---
+/// Word to type, one grapheme after the other. A grapheme made of several
+/// chars, like a letter and a combining mark without composed form, needs
+/// a keystroke for each of its chars.
+#[derive(Debug)]
+pub struct Exercise {
+    graphemes: Vec<String>,
+    states: Vec<Typing>,
+    mistaken: Vec<bool>,
+    progress: usize,
+    // chars already typed in the grapheme under progress
+    partial: usize,
+    policy: ErrorPolicy
+}
 
 impl Exercise {
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.graphemes.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.graphemes.is_empty()
+    }
+
+    /// Columns taken by the grapheme on the terminal.
+    pub fn width(&self, i: usize) -> usize {
+        word::width(&self.graphemes[i])
+    }
+
+    /// Column of the grapheme from the start of the word.
+    pub fn column(&self, i: usize) -> usize {
+        (0..i).map(|j| self.width(j)).sum()
+    }
+
+    /// Next char to type, if any left.
+    pub fn expected(&self) -> Option<char> {
+        self.graphemes.get(self.progress).and_then(|g| g.chars().nth(self.partial))
+    }
+
+    pub fn progress(&self) -> usize {
+        self.progress
+    }
+
+    /// Whether the grapheme under progress is partly typed.
+    pub fn is_partial(&self) -> bool {
+        self.partial > 0
+    }
+
+    pub fn state(&self, i: usize) -> Typing {
+        self.states[i]
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress >= self.graphemes.len()
+    }
+
+    /// A word is failed as soon as one of its characters was mistyped,
+    /// even if it got corrected afterwards.
+    #[allow(dead_code)]
+    pub fn is_failed(&self) -> bool {
+        self.mistaken.iter().any(|m| *m)
+    }
+
+    pub fn type_char(&mut self, typed: char) -> Stroke {
+        if self.is_done() {
+            return Stroke::Ignored;
+        }
+
+        let i = self.progress;
+        if Some(typed) == self.expected() {
+            self.partial += 1;
+            if self.partial == self.graphemes[i].chars().count() {
+                self.states[i] = match self.mistaken[i] {
+                    false => Typing::Correct,
+                    true => Typing::Corrected
+                };
+                self.progress += 1;
+                self.partial = 0;
+            }
+            Stroke::Accepted
+        } else {
+            // the grapheme is typed again from its first char
+            self.states[i] = Typing::Incorrect(typed);
+            self.mistaken[i] = true;
+            self.partial = 0;
+            if self.policy.advance_on_error() {
+                self.progress += 1;
+            }
+            Stroke::Rejected
+        }
+    }
+
+    /// Step back on the last typed character, if the policy allows it.
+    /// The character keeps the memory of a previous mistake.
+    pub fn backspace(&mut self) -> bool {
+        self.policy.allow_correction() && self.reopen()
+    }
+
+    /// Step back on the last typed character whatever the policy, for the
+    /// session to go back into a completed word.
+    pub fn reopen(&mut self) -> bool {
+        if self.progress == 0 && self.partial == 0 {
+            return false;
+        }
+
+        // a partly typed grapheme is erased at once
+        if self.partial == 0 {
+            self.progress -= 1;
+        }
+        self.partial = 0;
+        self.states[self.progress] = Typing::Pending;
+        true
+    }
+
+    /// Forget everything typed so far.
+    #[allow(dead_code)]
+    pub fn reset(&mut self) {
+        self.progress = 0;
+        self.partial = 0;
+        for state in self.states.iter_mut() {
+            *state = Typing::Pending;
+        }
+        for mistaken in self.mistaken.iter_mut() {
+            *mistaken = false;
+        }
     }
 }
 
 impl Index<usize> for Exercise {
-    type Output = char;
+    type Output = str;
+
+    fn index(&self, i: usize) -> &str {
+        &self.graphemes[i]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn block_waits_on_mistyped_char() {
+        use super::*;
+        let mut ex = with_policy(&Word::new("ab"), ErrorPolicy::Block);
+
+        assert_eq!(ex.type_char('x'), Stroke::Rejected);
+        assert_eq!(ex.progress(), 0);
+        assert_eq!(ex.state(0), Typing::Incorrect('x'));
+
+        assert_eq!(ex.type_char('a'), Stroke::Accepted);
+        assert_eq!(ex.state(0), Typing::Corrected);
+        assert_eq!(ex.type_char('b'), Stroke::Accepted);
+        assert!(ex.is_done());
+        assert!(ex.is_failed());
+    }
+
+    #[test]
+    fn forward_moves_on_and_forbids_correction() {
+        use super::*;
+        let mut ex = with_policy(&Word::new("ab"), ErrorPolicy::Forward);
+
+        assert_eq!(ex.type_char('x'), Stroke::Rejected);
+        assert_eq!(ex.progress(), 1);
+        assert!(!ex.backspace());
+        assert_eq!(ex.progress(), 1);
+
+        assert_eq!(ex.type_char('b'), Stroke::Accepted);
+        assert!(ex.is_done());
+        assert_eq!(ex.state(0), Typing::Incorrect('x'));
+    }
+
+    #[test]
+    fn correctable_allows_backspace() {
+        use super::*;
+        let mut ex = with_policy(&Word::new("ab"), ErrorPolicy::Correctable);
+
+        assert_eq!(ex.type_char('x'), Stroke::Rejected);
+        assert!(ex.backspace());
+        assert_eq!(ex.progress(), 0);
+
+        assert_eq!(ex.type_char('a'), Stroke::Accepted);
+        assert_eq!(ex.state(0), Typing::Corrected);
+        assert_eq!(ex.type_char('b'), Stroke::Accepted);
+        assert_eq!(ex.state(1), Typing::Correct);
+        assert_eq!(ex.type_char('c'), Stroke::Ignored);
+    }
+
+    #[test]
+    fn untouched_word_is_not_failed() {
+        use super::*;
+        let mut ex = with_policy(&Word::new("ab"), ErrorPolicy::Correctable);
+
+        ex.type_char('a');
+        ex.backspace();
+        ex.type_char('a');
+        ex.type_char('b');
+        assert!(!ex.is_failed());
+    }
+
+    #[test]
+    fn graphemes_of_several_chars() {
+        use super::*;
+        // no composed form for this one
+        let mut ex = with_policy(&Word::new("q\u{301}e\u{301}"), ErrorPolicy::Correctable);
+        assert_eq!(ex.len(), 2);
+        assert_eq!(&ex[0], "q\u{301}");
+
+        assert_eq!(ex.type_char('q'), Stroke::Accepted);
+        assert_eq!(ex.progress(), 0);
+        assert_eq!(ex.expected(), Some('\u{301}'));
+        assert!(ex.backspace());
+        assert_eq!(ex.expected(), Some('q'));
+
+        ex.type_char('q');
+        assert_eq!(ex.type_char('\u{301}'), Stroke::Accepted);
+        assert_eq!(ex.state(0), Typing::Correct);
+        // decomposed in the word, typed composed
+        assert_eq!(ex.type_char('\u{e9}'), Stroke::Accepted);
+        assert!(ex.is_done());
+    }
+
+    #[test]
+    fn columns_of_wide_chars() {
+        use super::*;
+        let ex = new(&Word::new("a漢字b"));
 
-    fn index(&self, i: usize) -> &char {
-        &self.0[i]
+        assert_eq!(ex.len(), 4);
+        assert_eq!(ex.width(1), 2);
+        assert_eq!((0..4).map(|i| ex.column(i)).collect::<Vec<_>>(), vec![0, 1, 3, 5]);
     }
 }
@@ -0,0 +1,355 @@
+use app::layout::{Layout, Level, Symbol};
+
+
+// Key widths are counted in quarters of a standard key, so the rows of a
+// staggered main block are 15 keys (60 quarters) wide. Only the
+// alphanumeric block is described: function keys, arrows and keypad are
+// never typed. The Return key of ISO and JIS spans two rows, and is
+// listed on both.
+type Table = &'static [&'static [(&'static str, u16)]];
+
+// keys typed by another finger than on a staggered keyboard
+type Fingers = &'static [(&'static str, Finger)];
+
+static GEOMETRIES: [(&str, usize, Table, Fingers); 4] = [
+    ("iso-105", 2, ISO_105, &[]),
+    ("ansi-104", 2, ANSI_104, &[]),
+    ("jis-109", 2, JIS_109, &[]),
+    ("typematrix", 2, TYPEMATRIX, TYPEMATRIX_FINGERS)
+];
+
+
+//---
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Finger {
+    LeftPinky,
+    LeftRing,
+    LeftMiddle,
+    LeftIndex,
+    Thumb,
+    RightIndex,
+    RightMiddle,
+    RightRing,
+    RightPinky
+}
+
+impl Finger {
+    pub fn all() -> [Finger; 9] {
+        [Finger::LeftPinky, Finger::LeftRing, Finger::LeftMiddle, Finger::LeftIndex, Finger::Thumb,
+            Finger::RightIndex, Finger::RightMiddle, Finger::RightRing, Finger::RightPinky]
+    }
+
+    pub fn is_left(&self) -> bool {
+        *self < Finger::Thumb
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Finger::LeftPinky => "left pinky",
+            Finger::LeftRing => "left ring",
+            Finger::LeftMiddle => "left middle",
+            Finger::LeftIndex => "left index",
+            Finger::Thumb => "thumb",
+            Finger::RightIndex => "right index",
+            Finger::RightMiddle => "right middle",
+            Finger::RightRing => "right ring",
+            Finger::RightPinky => "right pinky"
+        }
+    }
+
+    /// Touch typing finger for a key, from its XKB code.
+    pub fn for_key(code: &str) -> Finger {
+        let column = |prefix: &str| code.strip_prefix(prefix).and_then(|n| n.parse::<u16>().ok());
+        let column = column("AE").or_else(|| column("AD"))
+            .or_else(|| column("AC"))
+            .or_else(|| column("AB"));
+
+        match (code, column) {
+            (_, Some(1)) => Finger::LeftPinky,
+            (_, Some(2)) => Finger::LeftRing,
+            (_, Some(3)) => Finger::LeftMiddle,
+            (_, Some(4)) | (_, Some(5)) => Finger::LeftIndex,
+            (_, Some(6)) | (_, Some(7)) => Finger::RightIndex,
+            (_, Some(8)) => Finger::RightMiddle,
+            (_, Some(9)) => Finger::RightRing,
+            ("TLDE", _) | ("LSGT", _) | ("TAB", _) | ("CAPS", _) | ("LFSH", _) | ("LCTL", _) => Finger::LeftPinky,
+            ("SPCE", _) | ("LALT", _) | ("RALT", _) | ("MUHE", _) | ("HENK", _) => Finger::Thumb,
+            _ => Finger::RightPinky
+        }
+    }
+}
+
+
+//---
+/// A physical key: its code, horizontal position and width, in quarters
+/// of a standard key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slot {
+    pub code: String,
+    pub x: u16,
+    pub width: u16,
+    pub finger: Finger
+}
+
+
+//---
+/// Where and how to type a char on a physical keyboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placement {
+    pub code: String,
+    pub row: usize,
+    pub finger: Finger,
+    pub level: Level,
+    // codes of the modifier keys to hold
+    pub modifiers: Vec<String>,
+    // dead key to type first, for a char without key of its own
+    pub dead: Option<Box<Placement>>
+}
+
+
+//---
+#[derive(Debug, Clone)]
+pub struct Geometry {
+    pub name: String,
+    pub rows: Vec<Vec<Slot>>,
+    pub home_row: usize
+}
+
+impl Geometry {
+    pub fn builtin_names() -> Vec<&'static str> {
+        GEOMETRIES.iter().map(|&(name, _, _, _)| name).collect()
+    }
+
+    pub fn builtin(name: &str) -> Option<Geometry> {
+        GEOMETRIES.iter()
+            .find(|&&(n, _, _, _)| n == name)
+            .map(|&(n, home_row, table, fingers)| {
+                let rows = table.iter()
+                    .map(|row| {
+                        let mut x = 0;
+                        row.iter()
+                            .map(|&(code, width)| {
+                                let finger = fingers.iter()
+                                    .find(|&&(c, _)| c == code)
+                                    .map_or_else(|| Finger::for_key(code), |&(_, finger)| finger);
+                                let slot = Slot { code: code.to_string(), x, width, finger };
+                                x += width;
+                                slot
+                            })
+                            .collect()
+                    })
+                    .collect();
+                Geometry { name: n.to_string(), rows, home_row }
+            })
+    }
+
+    /// Width of the widest row, in quarters of a key.
+    pub fn width(&self) -> u16 {
+        self.rows.iter()
+            .filter_map(|row| row.last().map(|s| s.x + s.width))
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn slot(&self, code: &str) -> Option<(usize, &Slot)> {
+        self.rows.iter()
+            .enumerate()
+            .filter_map(|(i, row)| row.iter().find(|s| s.code == code).map(|s| (i, s)))
+            .next()
+    }
+
+    /// Physical key, finger and modifiers to type a char with the layout.
+    /// Shift is held with the other hand, AltGr with the right thumb. A
+    /// char without key of its own is the base char, after its dead key.
+    pub fn locate(&self, layout: &Layout, c: char) -> Option<Placement> {
+        if let Some(placement) = self.locate_symbol(layout, Symbol::Char(c)) {
+            return Some(placement);
+        }
+        let (mark, base) = layout.dead_keys().decompose(c)?;
+        let dead = self.locate_symbol(layout, Symbol::Dead(mark))?;
+        let mut placement = self.locate_symbol(layout, Symbol::Char(base))?;
+        placement.dead = Some(Box::new(dead));
+        Some(placement)
+    }
+
+    /// Same as `locate`, for any symbol, dead keys included.
+    pub fn locate_symbol(&self, layout: &Layout, symbol: Symbol) -> Option<Placement> {
+        let stroke = layout.locate_symbol(symbol)?;
+        let (row, slot) = self.slot(&stroke.code)?;
+
+        let mut modifiers = Vec::new();
+        if stroke.level.needs_shift() {
+            modifiers.push(match slot.finger.is_left() {
+                true => "RTSH".to_string(),
+                false => "LFSH".to_string()
+            });
+        }
+        if stroke.level.needs_altgr() {
+            modifiers.push("RALT".to_string());
+        }
+
+        Some(Placement {
+            code: stroke.code,
+            row,
+            finger: slot.finger,
+            level: stroke.level,
+            modifiers,
+            dead: None
+        })
+    }
+}
+
+
+static ISO_105: Table = &[
+    &[("TLDE", 4), ("AE01", 4), ("AE02", 4), ("AE03", 4), ("AE04", 4), ("AE05", 4), ("AE06", 4), ("AE07", 4),
+        ("AE08", 4), ("AE09", 4), ("AE10", 4), ("AE11", 4), ("AE12", 4), ("BKSP", 8)],
+    &[("TAB", 6), ("AD01", 4), ("AD02", 4), ("AD03", 4), ("AD04", 4), ("AD05", 4), ("AD06", 4), ("AD07", 4),
+        ("AD08", 4), ("AD09", 4), ("AD10", 4), ("AD11", 4), ("AD12", 4), ("RTRN", 6)],
+    &[("CAPS", 7), ("AC01", 4), ("AC02", 4), ("AC03", 4), ("AC04", 4), ("AC05", 4), ("AC06", 4), ("AC07", 4),
+        ("AC08", 4), ("AC09", 4), ("AC10", 4), ("AC11", 4), ("BKSL", 4), ("RTRN", 5)],
+    &[("LFSH", 5), ("LSGT", 4), ("AB01", 4), ("AB02", 4), ("AB03", 4), ("AB04", 4), ("AB05", 4), ("AB06", 4),
+        ("AB07", 4), ("AB08", 4), ("AB09", 4), ("AB10", 4), ("RTSH", 11)],
+    &[("LCTL", 5), ("LWIN", 5), ("LALT", 5), ("SPCE", 25), ("RALT", 5), ("RWIN", 5), ("MENU", 5), ("RCTL", 5)]
+];
+
+static ANSI_104: Table = &[
+    &[("TLDE", 4), ("AE01", 4), ("AE02", 4), ("AE03", 4), ("AE04", 4), ("AE05", 4), ("AE06", 4), ("AE07", 4),
+        ("AE08", 4), ("AE09", 4), ("AE10", 4), ("AE11", 4), ("AE12", 4), ("BKSP", 8)],
+    &[("TAB", 6), ("AD01", 4), ("AD02", 4), ("AD03", 4), ("AD04", 4), ("AD05", 4), ("AD06", 4), ("AD07", 4),
+        ("AD08", 4), ("AD09", 4), ("AD10", 4), ("AD11", 4), ("AD12", 4), ("BKSL", 6)],
+    &[("CAPS", 7), ("AC01", 4), ("AC02", 4), ("AC03", 4), ("AC04", 4), ("AC05", 4), ("AC06", 4), ("AC07", 4),
+        ("AC08", 4), ("AC09", 4), ("AC10", 4), ("AC11", 4), ("RTRN", 9)],
+    &[("LFSH", 9), ("AB01", 4), ("AB02", 4), ("AB03", 4), ("AB04", 4), ("AB05", 4), ("AB06", 4), ("AB07", 4),
+        ("AB08", 4), ("AB09", 4), ("AB10", 4), ("RTSH", 11)],
+    &[("LCTL", 5), ("LWIN", 5), ("LALT", 5), ("SPCE", 25), ("RALT", 5), ("RWIN", 5), ("MENU", 5), ("RCTL", 5)]
+];
+
+static JIS_109: Table = &[
+    &[("TLDE", 4), ("AE01", 4), ("AE02", 4), ("AE03", 4), ("AE04", 4), ("AE05", 4), ("AE06", 4), ("AE07", 4),
+        ("AE08", 4), ("AE09", 4), ("AE10", 4), ("AE11", 4), ("AE12", 4), ("AE13", 4), ("BKSP", 4)],
+    &[("TAB", 6), ("AD01", 4), ("AD02", 4), ("AD03", 4), ("AD04", 4), ("AD05", 4), ("AD06", 4), ("AD07", 4),
+        ("AD08", 4), ("AD09", 4), ("AD10", 4), ("AD11", 4), ("AD12", 4), ("RTRN", 6)],
+    &[("CAPS", 7), ("AC01", 4), ("AC02", 4), ("AC03", 4), ("AC04", 4), ("AC05", 4), ("AC06", 4), ("AC07", 4),
+        ("AC08", 4), ("AC09", 4), ("AC10", 4), ("AC11", 4), ("BKSL", 4), ("RTRN", 5)],
+    &[("LFSH", 9), ("AB01", 4), ("AB02", 4), ("AB03", 4), ("AB04", 4), ("AB05", 4), ("AB06", 4), ("AB07", 4),
+        ("AB08", 4), ("AB09", 4), ("AB10", 4), ("AB11", 4), ("RTSH", 7)],
+    &[("LCTL", 5), ("LWIN", 4), ("LALT", 4), ("MUHE", 4), ("SPCE", 18), ("HENK", 4), ("HKTG", 4), ("RALT", 4),
+        ("RWIN", 4), ("MENU", 4), ("RCTL", 5)]
+];
+
+// TypeMatrix 2030: straight columns, with a middle column between hands
+static TYPEMATRIX: Table = &[
+    &[("TLDE", 4), ("AE01", 4), ("AE02", 4), ("AE03", 4), ("AE04", 4), ("AE05", 4), ("DELE", 4), ("AE06", 4),
+        ("AE07", 4), ("AE08", 4), ("AE09", 4), ("AE10", 4), ("AE11", 4), ("AE12", 4)],
+    &[("TAB", 4), ("AD01", 4), ("AD02", 4), ("AD03", 4), ("AD04", 4), ("AD05", 4), ("BKSP", 4), ("AD06", 4),
+        ("AD07", 4), ("AD08", 4), ("AD09", 4), ("AD10", 4), ("AD11", 4), ("AD12", 4)],
+    &[("CAPS", 4), ("AC01", 4), ("AC02", 4), ("AC03", 4), ("AC04", 4), ("AC05", 4), ("RTRN", 4), ("AC06", 4),
+        ("AC07", 4), ("AC08", 4), ("AC09", 4), ("AC10", 4), ("AC11", 4), ("BKSL", 4)],
+    &[("LFSH", 4), ("AB01", 4), ("AB02", 4), ("AB03", 4), ("AB04", 4), ("AB05", 4), ("LSGT", 4), ("AB06", 4),
+        ("AB07", 4), ("AB08", 4), ("AB09", 4), ("AB10", 4), ("RTSH", 8)],
+    &[("LCTL", 8), ("LWIN", 4), ("LALT", 4), ("SPCE", 24), ("RALT", 4), ("MENU", 4), ("RCTL", 8)]
+];
+
+// the middle column is typed by the index of the hand doing its job on
+// other keyboards
+static TYPEMATRIX_FINGERS: Fingers = &[
+    ("DELE", Finger::RightIndex), ("BKSP", Finger::RightIndex), ("RTRN", Finger::RightIndex),
+    ("LSGT", Finger::LeftIndex)
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn all_builtins_exist() {
+        for name in Geometry::builtin_names() {
+            let geometry = Geometry::builtin(name).expect("missing builtin");
+            let width = match name {
+                "typematrix" => 56,
+                _ => 60
+            };
+            assert_eq!(geometry.width(), width, "{} width", name);
+            for (i, row) in geometry.rows.iter().enumerate() {
+                let last = row.last().unwrap();
+                assert_eq!(last.x + last.width, width, "{} row {} width", name, i);
+            }
+            assert!(geometry.slot("AC01").is_some());
+            assert!(geometry.slot("SPCE").is_some());
+        }
+        assert!(Geometry::builtin("unknown").is_none());
+    }
+
+    #[test]
+    fn home_row_fingers() {
+        let geometry = Geometry::builtin("ansi-104").unwrap();
+        let fingers: Vec<Finger> = geometry.rows[geometry.home_row].iter()
+            .filter(|s| s.code.starts_with("AC"))
+            .map(|s| s.finger)
+            .collect();
+
+        assert_eq!(&fingers[..9], &[Finger::LeftPinky, Finger::LeftRing, Finger::LeftMiddle,
+            Finger::LeftIndex, Finger::LeftIndex, Finger::RightIndex, Finger::RightIndex,
+            Finger::RightMiddle, Finger::RightRing]);
+    }
+
+    #[test]
+    fn middle_column_fingers() {
+        let geometry = Geometry::builtin("typematrix").unwrap();
+        let finger = |code: &str| geometry.slot(code).map(|(_, slot)| slot.finger);
+
+        assert_eq!(finger("BKSP"), Some(Finger::RightIndex));
+        assert_eq!(finger("RTRN"), Some(Finger::RightIndex));
+        assert_eq!(finger("LSGT"), Some(Finger::LeftIndex));
+        assert_eq!(finger("RTSH"), Some(Finger::RightPinky));
+        assert_eq!(Geometry::builtin("ansi-104").unwrap().slot("BKSP").map(|(_, slot)| slot.finger),
+                   Some(Finger::RightPinky));
+    }
+
+    #[test]
+    fn locate_with_modifiers() {
+        let qwerty = Layout::builtin("qwerty-us").unwrap();
+        let bepo = Layout::builtin("bepo").unwrap();
+        let ansi = Geometry::builtin("ansi-104").unwrap();
+        let iso = Geometry::builtin("iso-105").unwrap();
+
+        let a = ansi.locate(&qwerty, 'A').unwrap();
+        assert_eq!((a.code.as_str(), a.row, a.finger), ("AC01", 2, Finger::LeftPinky));
+        assert_eq!(a.modifiers, vec!["RTSH".to_string()]);
+
+        let oe = iso.locate(&bepo, 'Œ').unwrap();
+        assert_eq!((oe.code.as_str(), oe.finger), ("AD04", Finger::LeftIndex));
+        assert_eq!(oe.modifiers, vec!["RTSH".to_string(), "RALT".to_string()]);
+
+        let n = iso.locate(&bepo, 'N').unwrap();
+        assert_eq!(n.modifiers, vec!["LFSH".to_string()]);
+    }
+
+    #[test]
+    fn key_missing_from_geometry() {
+        let bepo = Layout::builtin("bepo").unwrap();
+
+        // bépo puts ê on the extra ISO key, else it takes the dead key
+        assert_eq!(Geometry::builtin("iso-105").unwrap().locate(&bepo, 'ê').unwrap().dead, None);
+        assert_eq!(Geometry::builtin("typematrix").unwrap().locate(&bepo, 'ê').unwrap().dead, None);
+        let e = Geometry::builtin("ansi-104").unwrap().locate(&bepo, 'ê').unwrap();
+        assert_eq!(e.code, "AC04");
+        assert_eq!(e.dead.map(|dead| dead.code), Some("AD06".to_string()));
+
+        // bépo has a dead caron, but no dead comma below
+        assert!(Geometry::builtin("ansi-104").unwrap().locate(&bepo, 'ž').is_some());
+        assert!(Geometry::builtin("ansi-104").unwrap().locate(&bepo, 'ș').is_none());
+    }
+
+    #[test]
+    fn locate_through_dead_keys() {
+        let azerty = Layout::builtin("azerty-fr").unwrap();
+        let iso = Geometry::builtin("iso-105").unwrap();
+
+        let e = iso.locate(&azerty, 'Ë').unwrap();
+        assert_eq!((e.code.as_str(), e.level), ("AD03", Level::Shift));
+        let dead = e.dead.unwrap();
+        assert_eq!((dead.code.as_str(), dead.level), ("AD11", Level::Shift));
+        assert_eq!(dead.modifiers, vec!["LFSH".to_string()]);
+    }
+}
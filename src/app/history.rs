@@ -0,0 +1,504 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::str;
+use std::time::Duration;
+
+use app::record::{Keystroke, Record};
+use app::stats;
+
+
+// version written in new files, and the newest one understood
+pub static VERSION: u32 = 1;
+
+
+//---
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(io::Error),
+    // line number (1-based) which cannot be understood
+    Malformed(usize),
+    // version of a file written by a newer release
+    UnsupportedVersion(u32)
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HistoryError::Io(ref err) => write!(f, "cannot access history: {}", err),
+            HistoryError::Malformed(line) => write!(f, "history is malformed at line {}", line),
+            HistoryError::UnsupportedVersion(version) => write!(f, "history version {} is not supported", version)
+        }
+    }
+}
+
+impl error::Error for HistoryError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            HistoryError::Io(ref err) => Some(err),
+            HistoryError::Malformed(_) | HistoryError::UnsupportedVersion(_) => None
+        }
+    }
+}
+
+impl From<io::Error> for HistoryError {
+    fn from(err: io::Error) -> HistoryError {
+        HistoryError::Io(err)
+    }
+}
+
+
+//---
+/// How many times a char, or a bigram, was expected and missed, and the
+/// time taken to type it right after the previous char of the word.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Count {
+    pub typed: usize,
+    pub missed: usize,
+    // keystrokes with a measured time
+    pub timed: usize,
+    pub time: Duration
+}
+
+impl Count {
+    /// Mean time to type it, if ever measured.
+    pub fn latency(&self) -> Option<Duration> {
+        match self.timed {
+            0 => None,
+            timed => Some(self.time / timed as u32)
+        }
+    }
+
+    pub fn add(&mut self, other: &Count) {
+        self.typed += other.typed;
+        self.missed += other.missed;
+        self.timed += other.timed;
+        self.time += other.time;
+    }
+}
+
+
+//---
+/// A finished session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    // seconds since the Unix epoch
+    pub date: u64,
+    // builtin layout, or absolute path of an XKB symbols file with its
+    // variant
+    pub layout: String,
+    // where the words came from
+    pub source: String,
+    pub duration: Duration,
+    pub keystrokes: usize,
+    pub errors: usize,
+    // per expected char
+    pub keys: BTreeMap<char, Count>,
+    // per pair of chars following each other in a word
+    pub bigrams: BTreeMap<(char, char), Count>
+}
+
+impl Entry {
+    pub fn new(date: u64, layout: &str, source: &str, record: &Record) -> Entry {
+        let mut keys: BTreeMap<char, Count> = BTreeMap::new();
+        let mut bigrams: BTreeMap<(char, char), Count> = BTreeMap::new();
+        let mut expected: HashMap<(usize, usize), char> = HashMap::new();
+        let mut previous: Option<&Keystroke> = None;
+
+        let chars = record.chars();
+        for keystroke in chars.iter() {
+            let mut count = Count { typed: 1, ..Count::default() };
+            if !keystroke.is_correct() {
+                count.missed = 1;
+            }
+            // timed only when typed right just after the previous char
+            if let Some(previous) = previous {
+                if keystroke.is_correct() && previous.is_correct() && previous.word == keystroke.word
+                    && previous.offset + 1 == keystroke.offset {
+                    count.timed = 1;
+                    count.time = keystroke.at - previous.at;
+                }
+            }
+
+            keys.entry(keystroke.expected).or_default().add(&count);
+            if keystroke.offset > 0 {
+                if let Some(&before) = expected.get(&(keystroke.word, keystroke.offset - 1)) {
+                    bigrams.entry((before, keystroke.expected)).or_default().add(&count);
+                }
+            }
+            expected.insert((keystroke.word, keystroke.offset), keystroke.expected);
+            previous = Some(keystroke);
+        }
+
+        Entry {
+            date,
+            layout: layout.to_string(),
+            source: source.to_string(),
+            duration: record.duration(),
+            keystrokes: chars.len(),
+            errors: keys.values().map(|c| c.missed).sum(),
+            keys,
+            bigrams
+        }
+    }
+
+    pub fn net_wpm(&self) -> f64 {
+        stats::net_wpm(self.keystrokes, self.errors, self.duration)
+    }
+
+    pub fn accuracy(&self) -> f64 {
+        stats::accuracy(self.keystrokes, self.errors)
+    }
+
+    fn write(&self, output: &mut dyn Write) -> io::Result<()> {
+        let keys: Vec<String> = self.keys.iter()
+            .map(|(&c, count)| format!("{:x}:{}:{}", c as u32, count.typed, count.missed))
+            .collect();
+        let times: Vec<String> = self.keys.iter()
+            .filter(|&(_, count)| count.timed > 0)
+            .map(|(&c, count)| format!("{:x}:{}:{}", c as u32, count.timed, count.time.as_millis()))
+            .collect();
+        let bigrams: Vec<String> = self.bigrams.iter()
+            .map(|(&(a, b), count)| format!("{:x}-{:x}:{}:{}:{}:{}", a as u32, b as u32,
+                                            count.typed, count.missed, count.timed, count.time.as_millis()))
+            .collect();
+        writeln!(output, "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                 self.date, clean(&self.layout), clean(&self.source), self.duration.as_millis(),
+                 self.keystrokes, self.errors, keys.join(" "), times.join(" "), bigrams.join(" "))
+    }
+
+    fn parse(line: &str) -> Option<Entry> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        // fields added by later versions come after, and are ignored
+        if fields.len() < 7 {
+            return None;
+        }
+
+        let mut keys: BTreeMap<char, Count> = BTreeMap::new();
+        for key in fields[6].split_whitespace() {
+            let parts: Vec<&str> = key.split(':').collect();
+            if parts.len() != 3 {
+                return None;
+            }
+            let count = keys.entry(parse_char(parts[0])?).or_default();
+            count.typed = parts[1].parse().ok()?;
+            count.missed = parts[2].parse().ok()?;
+        }
+
+        // optional fields, missing from older histories
+        for key in fields.get(7).map_or("", |f| *f).split_whitespace() {
+            let parts: Vec<&str> = key.split(':').collect();
+            if parts.len() != 3 {
+                return None;
+            }
+            let count = keys.entry(parse_char(parts[0])?).or_default();
+            count.timed = parts[1].parse().ok()?;
+            count.time = Duration::from_millis(parts[2].parse().ok()?);
+        }
+
+        let mut bigrams = BTreeMap::new();
+        for bigram in fields.get(8).map_or("", |f| *f).split_whitespace() {
+            let parts: Vec<&str> = bigram.split(':').collect();
+            let chars: Vec<&str> = parts[0].split('-').collect();
+            if parts.len() != 5 || chars.len() != 2 {
+                return None;
+            }
+            bigrams.insert((parse_char(chars[0])?, parse_char(chars[1])?), Count {
+                typed: parts[1].parse().ok()?,
+                missed: parts[2].parse().ok()?,
+                timed: parts[3].parse().ok()?,
+                time: Duration::from_millis(parts[4].parse().ok()?)
+            });
+        }
+
+        Some(Entry {
+            date: fields[0].parse().ok()?,
+            layout: fields[1].to_string(),
+            source: fields[2].to_string(),
+            duration: Duration::from_millis(fields[3].parse().ok()?),
+            keystrokes: fields[4].parse().ok()?,
+            errors: fields[5].parse().ok()?,
+            keys,
+            bigrams
+        })
+    }
+}
+
+// chars are written as their hexadecimal code point
+fn parse_char(hex: &str) -> Option<char> {
+    u32::from_str_radix(hex, 16).ok().and_then(::std::char::from_u32)
+}
+
+// fields are separated by tabs, one entry per line
+fn clean(field: &str) -> String {
+    field.replace(['\t', '\n', '\r'], " ")
+}
+
+
+//---
+/// Figures of the sessions of a period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trend {
+    // seconds since the Unix epoch
+    pub start: u64,
+    pub sessions: usize,
+    pub net_wpm: f64,
+    pub accuracy: f64
+}
+
+
+//---
+#[derive(Debug, Default)]
+pub struct History {
+    pub entries: Vec<Entry>
+}
+
+/// `$XDG_DATA_HOME/typing_trainer`, the data home defaulting to
+/// `~/.local/share`.
+pub fn data_dir() -> Option<PathBuf> {
+    let data_home = env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local").join("share")))?;
+    Some(data_home.join("typing_trainer"))
+}
+
+pub fn default_path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("history"))
+}
+
+impl History {
+    /// Load a history file, a missing file being an empty history.
+    pub fn load(path: &Path) -> Result<History, HistoryError> {
+        match File::open(path) {
+            Ok(file) => History::read(BufReader::new(file)),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(HistoryError::Io(err))
+        }
+    }
+
+    /// Read a history.
+    ///
+    /// A `@version <n>` line comes first, then one session per line with
+    /// tab-separated fields: date, layout, source, duration in milliseconds,
+    /// keystrokes, errors and the per-key counts, as `<hex char>:<typed>:<missed>`
+    /// separated by spaces. Optional fields follow: the per-key times, as
+    /// `<hex char>:<timed>:<milliseconds>`, and the per-bigram counts, as
+    /// `<hex char>-<hex char>:<typed>:<missed>:<timed>:<milliseconds>`.
+    /// Lines starting with `#` are comments.
+    pub fn read<R: BufRead>(input: R) -> Result<History, HistoryError> {
+        let mut entries = Vec::new();
+        let mut version = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line?;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix("@version ") {
+                let read: u32 = directive.trim().parse().map_err(|_| HistoryError::Malformed(line_number))?;
+                if read > VERSION {
+                    return Err(HistoryError::UnsupportedVersion(read));
+                }
+                version = Some(read);
+                continue;
+            }
+
+            match (version, Entry::parse(&line)) {
+                (Some(_), Some(entry)) => entries.push(entry),
+                _ => return Err(HistoryError::Malformed(line_number))
+            }
+        }
+
+        Ok(History { entries })
+    }
+
+    pub fn write(&self, output: &mut dyn Write) -> io::Result<()> {
+        writeln!(output, "@version {}", VERSION)?;
+        for entry in self.entries.iter() {
+            entry.write(output)?;
+        }
+        Ok(())
+    }
+
+    /// Add a session at the end of a history file, creating it if needed.
+    pub fn append(path: &Path, entry: &Entry) -> Result<(), HistoryError> {
+        // refuse to mix versions in the same file
+        let known = History::load(path)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if known.entries.is_empty() && file.metadata()?.len() == 0 {
+            writeln!(file, "@version {}", VERSION)?;
+        }
+        entry.write(&mut file)?;
+        Ok(())
+    }
+
+    /// Sum of the per-key counts of all sessions.
+    pub fn key_counts(&self) -> BTreeMap<char, Count> {
+        let mut total: BTreeMap<char, Count> = BTreeMap::new();
+        for entry in self.entries.iter() {
+            for (&c, count) in entry.keys.iter() {
+                total.entry(c).or_default().add(count);
+            }
+        }
+        total
+    }
+
+    /// Sum of the per-bigram counts of all sessions.
+    pub fn bigram_counts(&self) -> BTreeMap<(char, char), Count> {
+        let mut total: BTreeMap<(char, char), Count> = BTreeMap::new();
+        for entry in self.entries.iter() {
+            for (&bigram, count) in entry.bigrams.iter() {
+                total.entry(bigram).or_default().add(count);
+            }
+        }
+        total
+    }
+
+    /// Speed and accuracy per period of time, the oldest first, only
+    /// counting the sessions with the layout if given.
+    pub fn trend(&self, layout: Option<&str>, period: Duration) -> Vec<Trend> {
+        let period = period.as_secs().max(1);
+
+        let mut periods: BTreeMap<u64, Vec<&Entry>> = BTreeMap::new();
+        for entry in self.entries.iter().filter(|e| layout.is_none_or(|l| e.layout == l)) {
+            periods.entry(entry.date - entry.date % period).or_default().push(entry);
+        }
+
+        periods.into_iter()
+            .map(|(start, entries)| {
+                let keystrokes = entries.iter().map(|e| e.keystrokes).sum();
+                let errors = entries.iter().map(|e| e.errors).sum();
+                let duration = entries.iter().map(|e| e.duration).sum();
+                Trend {
+                    start,
+                    sessions: entries.len(),
+                    net_wpm: stats::net_wpm(keystrokes, errors, duration),
+                    accuracy: stats::accuracy(keystrokes, errors)
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::record::Kind;
+
+    static DAY: u64 = 24 * 60 * 60;
+
+    fn entry(date: u64, layout: &str, keys: &str, typed: &str) -> Entry {
+        let mut record = Record::default();
+        for (i, (expected, typed)) in keys.chars().zip(typed.chars()).enumerate() {
+            record.push(Keystroke { at: Duration::from_secs(i as u64), kind: Kind::Char, expected, typed, word: 0, offset: i });
+        }
+        Entry::new(date, layout, "bundled:en", &record)
+    }
+
+    #[test]
+    fn write_then_read() {
+        let history = History { entries: vec![
+            entry(DAY, "bepo", "a é", "a éx"),
+            entry(2 * DAY, "qwerty-us", "to", "tx")
+        ] };
+
+        let mut text = Vec::new();
+        history.write(&mut text).unwrap();
+        let read = History::read(&text[..]).expect("reading failed");
+
+        assert!(text.starts_with(b"@version 1\n"));
+        assert_eq!(read.entries, history.entries);
+        let space = read.entries[0].keys[&' '];
+        assert_eq!((space.typed, space.missed), (1, 0));
+        let o = read.entries[1].keys[&'o'];
+        assert_eq!((o.typed, o.missed), (1, 1));
+    }
+
+    #[test]
+    fn time_bigrams_typed_in_a_row() {
+        let mut record = Record::default();
+        for &(ms, expected, typed, offset) in [(0, 't', 't', 0), (300, 'h', 'x', 1), (400, 'h', 'h', 1),
+                                                (500, 'e', 'e', 2)].iter() {
+            record.push(Keystroke { at: Duration::from_millis(ms), kind: Kind::Char, expected, typed, word: 0, offset });
+        }
+        let entry = Entry::new(0, "bepo", "test", &record);
+
+        let th = entry.bigrams[&('t', 'h')];
+        assert_eq!((th.typed, th.missed, th.latency()), (2, 1, None));
+        let he = entry.bigrams[&('h', 'e')];
+        assert_eq!((he.typed, he.missed, he.latency()), (1, 0, Some(Duration::from_millis(100))));
+        assert_eq!(entry.keys[&'e'].latency(), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn versions() {
+        let line = "86400\tbepo\twords.txt\t3000\t4\t1\t61:3:1 62:1:0";
+
+        // optional fields may be missing
+        let history = History::read(format!("@version 1\n{}\n", line).as_bytes()).expect("reading failed");
+        let a = history.entries[0].keys[&'a'];
+        assert_eq!((a.typed, a.missed, a.timed), (3, 1, 0));
+        assert!(history.entries[0].bigrams.is_empty());
+
+        // fields added by a later release are ignored
+        let text = format!("# comment\n@version 1\n{}\t61:2:500\t61-62:1:0:1:200\tlater field\n", line);
+        let history = History::read(text.as_bytes()).expect("reading failed");
+        let a = history.entries[0].keys[&'a'];
+        assert_eq!((a.typed, a.missed, a.latency()), (3, 1, Some(Duration::from_millis(250))));
+        assert_eq!(history.entries[0].bigrams[&('a', 'b')].latency(), Some(Duration::from_millis(200)));
+
+        match History::read(format!("@version 2\n{}\n", line).as_bytes()) {
+            Err(HistoryError::UnsupportedVersion(2)) => (),
+            other => panic!("unexpected {:?}", other)
+        }
+        match History::read(format!("{}\n", line).as_bytes()) {
+            Err(HistoryError::Malformed(1)) => (),
+            other => panic!("unexpected {:?}", other)
+        }
+    }
+
+    #[test]
+    fn trend_per_day() {
+        let history = History { entries: vec![
+            entry(DAY + 10, "bepo", "abcd", "abxd"),
+            entry(DAY + 20, "bepo", "abcd", "abcd"),
+            entry(DAY + 30, "qwerty-us", "ab", "xx"),
+            entry(3 * DAY, "bepo", "abcd", "abcd")
+        ] };
+
+        let trend = history.trend(Some("bepo"), Duration::from_secs(DAY));
+        assert_eq!(trend.iter().map(|t| (t.start, t.sessions)).collect::<Vec<_>>(), vec![(DAY, 2), (3 * DAY, 1)]);
+        assert!((trend[0].accuracy - 7.0 / 8.0).abs() < 1e-9);
+        assert!((trend[1].accuracy - 1.0).abs() < 1e-9);
+
+        assert_eq!(history.trend(None, Duration::from_secs(DAY))[0].sessions, 3);
+        let a = history.key_counts()[&'a'];
+        assert_eq!((a.typed, a.missed), (4, 1));
+    }
+
+    #[test]
+    fn append_to_file() {
+        let dir = env::temp_dir().join(format!("typing_trainer_history_{}", ::std::process::id()));
+        let path = dir.join("nested").join("history");
+
+        History::append(&path, &entry(DAY, "bepo", "ab", "ab")).unwrap();
+        History::append(&path, &entry(2 * DAY, "bepo", "ab", "xb")).unwrap();
+        let history = History::load(&path).expect("loading failed");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(history.entries.len(), 2);
+        assert_eq!(history.entries[1].errors, 1);
+        assert!(History::load(&path).unwrap().entries.is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+use app::layout::{Key, Symbol};
+
+
+// Each key lists its symbols for the base, Shift, AltGr and Shift+AltGr
+// levels, a space meaning nothing on this level. Combining marks
+// (U+0300 to U+036F) are dead keys. The space bar is added to all.
+type Table = &'static [(&'static str, &'static str)];
+
+pub static LAYOUTS: [(&str, Table); 5] = [
+    ("qwerty-us", QWERTY_US),
+    ("azerty-fr", AZERTY_FR),
+    ("dvorak", DVORAK),
+    ("bepo", BEPO),
+    ("colemak", COLEMAK)
+];
+
+
+pub fn keys(table: Table) -> Vec<Key> {
+    let mut keys: Vec<Key> = table.iter()
+        .map(|&(code, symbols)| {
+            let mut levels = [None; 4];
+            for (level, c) in symbols.chars().take(4).enumerate() {
+                if c != ' ' {
+                    levels[level] = Some(Symbol::from_char(c));
+                }
+            }
+            Key { code: code.to_string(), symbols: levels }
+        })
+        .collect();
+    keys.push(Key {
+        code: "SPCE".to_string(),
+        symbols: [Some(Symbol::Char(' ')), Some(Symbol::Char(' ')), None, None]
+    });
+    keys
+}
+
+
+static QWERTY_US: Table = &[
+    ("TLDE", "`~"),
+    ("AE01", "1!"), ("AE02", "2@"), ("AE03", "3#"), ("AE04", "4$"), ("AE05", "5%"), ("AE06", "6^"),
+    ("AE07", "7&"), ("AE08", "8*"), ("AE09", "9("), ("AE10", "0)"), ("AE11", "-_"), ("AE12", "=+"),
+    ("AD01", "qQ"), ("AD02", "wW"), ("AD03", "eE"), ("AD04", "rR"), ("AD05", "tT"), ("AD06", "yY"),
+    ("AD07", "uU"), ("AD08", "iI"), ("AD09", "oO"), ("AD10", "pP"), ("AD11", "[{"), ("AD12", "]}"),
+    ("AC01", "aA"), ("AC02", "sS"), ("AC03", "dD"), ("AC04", "fF"), ("AC05", "gG"), ("AC06", "hH"),
+    ("AC07", "jJ"), ("AC08", "kK"), ("AC09", "lL"), ("AC10", ";:"), ("AC11", "'\""),
+    ("BKSL", "\\|"),
+    ("AB01", "zZ"), ("AB02", "xX"), ("AB03", "cC"), ("AB04", "vV"), ("AB05", "bB"), ("AB06", "nN"),
+    ("AB07", "mM"), ("AB08", ",<"), ("AB09", ".>"), ("AB10", "/?")
+];
+
+static AZERTY_FR: Table = &[
+    ("TLDE", "²"),
+    ("AE01", "&1"), ("AE02", "é2~"), ("AE03", "\"3#"), ("AE04", "'4{"), ("AE05", "(5["), ("AE06", "-6|"),
+    ("AE07", "è7`"), ("AE08", "_8\\"), ("AE09", "ç9^"), ("AE10", "à0@"), ("AE11", ")°]"), ("AE12", "=+}"),
+    ("AD01", "aA"), ("AD02", "zZ"), ("AD03", "eE€"), ("AD04", "rR"), ("AD05", "tT"), ("AD06", "yY"),
+    ("AD07", "uU"), ("AD08", "iI"), ("AD09", "oO"), ("AD10", "pP"), ("AD11", "\u{302}\u{308}"), ("AD12", "$£¤"),
+    ("AC01", "qQ"), ("AC02", "sS"), ("AC03", "dD"), ("AC04", "fF"), ("AC05", "gG"), ("AC06", "hH"),
+    ("AC07", "jJ"), ("AC08", "kK"), ("AC09", "lL"), ("AC10", "mM"), ("AC11", "ù%"),
+    ("BKSL", "*µ"),
+    ("LSGT", "<>"),
+    ("AB01", "wW"), ("AB02", "xX"), ("AB03", "cC"), ("AB04", "vV"), ("AB05", "bB"), ("AB06", "nN"),
+    ("AB07", ",?"), ("AB08", ";."), ("AB09", ":/"), ("AB10", "!§")
+];
+
+static DVORAK: Table = &[
+    ("TLDE", "`~"),
+    ("AE01", "1!"), ("AE02", "2@"), ("AE03", "3#"), ("AE04", "4$"), ("AE05", "5%"), ("AE06", "6^"),
+    ("AE07", "7&"), ("AE08", "8*"), ("AE09", "9("), ("AE10", "0)"), ("AE11", "[{"), ("AE12", "]}"),
+    ("AD01", "'\""), ("AD02", ",<"), ("AD03", ".>"), ("AD04", "pP"), ("AD05", "yY"), ("AD06", "fF"),
+    ("AD07", "gG"), ("AD08", "cC"), ("AD09", "rR"), ("AD10", "lL"), ("AD11", "/?"), ("AD12", "=+"),
+    ("AC01", "aA"), ("AC02", "oO"), ("AC03", "eE"), ("AC04", "uU"), ("AC05", "iI"), ("AC06", "dD"),
+    ("AC07", "hH"), ("AC08", "tT"), ("AC09", "nN"), ("AC10", "sS"), ("AC11", "-_"),
+    ("BKSL", "\\|"),
+    ("AB01", ";:"), ("AB02", "qQ"), ("AB03", "jJ"), ("AB04", "kK"), ("AB05", "xX"), ("AB06", "bB"),
+    ("AB07", "mM"), ("AB08", "wW"), ("AB09", "vV"), ("AB10", "zZ")
+];
+
+static BEPO: Table = &[
+    ("TLDE", "$#–¶"),
+    ("AE01", "\"1—„"), ("AE02", "«2<“"), ("AE03", "»3>”"), ("AE04", "(4[≤"), ("AE05", ")5]≥"), ("AE06", "@6^ "),
+    ("AE07", "+7±¬"), ("AE08", "-8−¼"), ("AE09", "/9÷½"), ("AE10", "*0×¾"), ("AE11", "=°≠′"), ("AE12", "%`‰″"),
+    ("AD01", "bB|¦"), ("AD02", "éÉ\u{301}\u{30b}"), ("AD03", "pP&§"), ("AD04", "oOœŒ"), ("AD05", "èÈ\u{300}`"),
+    ("AD06", "\u{302}!¡ "), ("AD07", "vV\u{30c} "), ("AD08", "dDðÐ"), ("AD09", "lL/ "), ("AD10", "jJĳĲ"),
+    ("AD11", "zZəƏ"), ("AD12", "wW\u{306} "),
+    ("AC01", "aAæÆ"), ("AC02", "uUùÙ"), ("AC03", "iI\u{308}\u{307}"), ("AC04", "eE€¤"), ("AC05", ",;’ "),
+    ("AC06", "cC©ſ"), ("AC07", "tTþÞ"), ("AC08", "sSßẞ"), ("AC09", "rR®™"), ("AC10", "nN\u{303}¬"),
+    ("AC11", "mM\u{304}º"),
+    ("BKSL", "çÇ\u{327},"),
+    ("LSGT", "êÊ/^"),
+    ("AB01", "àÀ\\‚"), ("AB02", "yY{‘"), ("AB03", "xX}’"), ("AB04", ".:…·"), ("AB05", "kK~‑"),
+    ("AB06", "'?¿\u{309}"), ("AB07", "qQ\u{30a}\u{323}"), ("AB08", "gG µ"), ("AB09", "hH†‡"), ("AB10", "fF\u{328}ª")
+];
+
+static COLEMAK: Table = &[
+    ("TLDE", "`~"),
+    ("AE01", "1!"), ("AE02", "2@"), ("AE03", "3#"), ("AE04", "4$"), ("AE05", "5%"), ("AE06", "6^"),
+    ("AE07", "7&"), ("AE08", "8*"), ("AE09", "9("), ("AE10", "0)"), ("AE11", "-_"), ("AE12", "=+"),
+    ("AD01", "qQ"), ("AD02", "wW"), ("AD03", "fF"), ("AD04", "pP"), ("AD05", "gG"), ("AD06", "jJ"),
+    ("AD07", "lL"), ("AD08", "uU"), ("AD09", "yY"), ("AD10", ";:"), ("AD11", "[{"), ("AD12", "]}"),
+    ("AC01", "aA"), ("AC02", "rR"), ("AC03", "sS"), ("AC04", "tT"), ("AC05", "dD"), ("AC06", "hH"),
+    ("AC07", "nN"), ("AC08", "eE"), ("AC09", "iI"), ("AC10", "oO"), ("AC11", "'\""),
+    ("BKSL", "\\|"),
+    ("AB01", "zZ"), ("AB02", "xX"), ("AB03", "cC"), ("AB04", "vV"), ("AB05", "bB"), ("AB06", "kK"),
+    ("AB07", "mM"), ("AB08", ",<"), ("AB09", ".>"), ("AB10", "/?")
+];
@@ -0,0 +1,90 @@
+use unicode_normalization::char::{compose, decompose_canonical};
+
+
+// spacing forms of the marks, sent by some terminals for a lone dead key,
+// and typed by a dead key followed by a space
+static SPACING: [(char, char); 13] = [
+    ('\u{300}', '`'),
+    ('\u{301}', '´'),
+    ('\u{302}', '^'),
+    ('\u{303}', '~'),
+    ('\u{304}', '¯'),
+    ('\u{306}', '˘'),
+    ('\u{307}', '˙'),
+    ('\u{308}', '¨'),
+    ('\u{30a}', '˚'),
+    ('\u{30b}', '˝'),
+    ('\u{30c}', 'ˇ'),
+    ('\u{327}', '¸'),
+    ('\u{328}', '˛')
+];
+
+
+//---
+/// Dead keys of a layout, each known by the combining mark it applies to
+/// the next char.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeadKeys {
+    marks: Vec<char>
+}
+
+impl DeadKeys {
+    pub fn new(marks: Vec<char>) -> DeadKeys {
+        DeadKeys { marks }
+    }
+
+    /// Mark of the dead key which sends this char on its own, either the
+    /// mark itself or its spacing form.
+    pub fn mark(&self, typed: char) -> Option<char> {
+        let mark = SPACING.iter()
+            .find(|&&(_, spacing)| spacing == typed)
+            .map_or(typed, |&(mark, _)| mark);
+        self.marks.iter().find(|&&m| m == mark).cloned()
+    }
+
+    /// Dead key mark and char to type after it, for a char made of a
+    /// single mark over another char.
+    pub fn decompose(&self, c: char) -> Option<(char, char)> {
+        let mut parts = Vec::new();
+        decompose_canonical(c, |part| parts.push(part));
+        match parts[..] {
+            [base, mark] if self.marks.contains(&mark) => Some((mark, base)),
+            _ => None
+        }
+    }
+}
+
+/// Char typed by a dead key followed by another char, if any: the mark
+/// over the char, or the spacing form of the mark after a space.
+pub fn apply(mark: char, base: char) -> Option<char> {
+    match base {
+        ' ' => SPACING.iter().find(|&&(m, _)| m == mark).map(|&(_, spacing)| spacing),
+        base => compose(base, mark)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compose_and_decompose() {
+        let dead_keys = DeadKeys::new(vec!['\u{302}', '\u{30c}']);
+
+        assert_eq!(dead_keys.mark('\u{302}'), Some('\u{302}'));
+        assert_eq!(dead_keys.mark('^'), Some('\u{302}'));
+        assert_eq!(dead_keys.mark('¨'), None);
+        assert_eq!(dead_keys.mark('e'), None);
+
+        assert_eq!(apply('\u{302}', 'e'), Some('ê'));
+        assert_eq!(apply('\u{30c}', 'a'), Some('ǎ'));
+        assert_eq!(apply('\u{302}', ' '), Some('^'));
+        assert_eq!(apply('\u{302}', 'x'), None);
+
+        assert_eq!(dead_keys.decompose('ê'), Some(('\u{302}', 'e')));
+        assert_eq!(dead_keys.decompose('ë'), None);
+        assert_eq!(dead_keys.decompose('e'), None);
+        // two marks
+        assert_eq!(dead_keys.decompose('ǖ'), None);
+    }
+}
@@ -0,0 +1,1775 @@
+// Generated from X11/keysymdef.h: keysyms producing a char, sorted by name.
+pub static CHARS: [(&str, char); 1715] = [
+    ("0", '0'),
+    ("1", '1'),
+    ("2", '2'),
+    ("3", '3'),
+    ("4", '4'),
+    ("5", '5'),
+    ("6", '6'),
+    ("7", '7'),
+    ("8", '8'),
+    ("9", '9'),
+    ("A", 'A'),
+    ("AE", '\u{c6}'),
+    ("Aacute", '\u{c1}'),
+    ("Abelowdot", '\u{1ea0}'),
+    ("Abreve", '\u{102}'),
+    ("Abreveacute", '\u{1eae}'),
+    ("Abrevebelowdot", '\u{1eb6}'),
+    ("Abrevegrave", '\u{1eb0}'),
+    ("Abrevehook", '\u{1eb2}'),
+    ("Abrevetilde", '\u{1eb4}'),
+    ("Acircumflex", '\u{c2}'),
+    ("Acircumflexacute", '\u{1ea4}'),
+    ("Acircumflexbelowdot", '\u{1eac}'),
+    ("Acircumflexgrave", '\u{1ea6}'),
+    ("Acircumflexhook", '\u{1ea8}'),
+    ("Acircumflextilde", '\u{1eaa}'),
+    ("Adiaeresis", '\u{c4}'),
+    ("Agrave", '\u{c0}'),
+    ("Ahook", '\u{1ea2}'),
+    ("Amacron", '\u{100}'),
+    ("Aogonek", '\u{104}'),
+    ("Arabic_0", '\u{660}'),
+    ("Arabic_1", '\u{661}'),
+    ("Arabic_2", '\u{662}'),
+    ("Arabic_3", '\u{663}'),
+    ("Arabic_4", '\u{664}'),
+    ("Arabic_5", '\u{665}'),
+    ("Arabic_6", '\u{666}'),
+    ("Arabic_7", '\u{667}'),
+    ("Arabic_8", '\u{668}'),
+    ("Arabic_9", '\u{669}'),
+    ("Arabic_ain", '\u{639}'),
+    ("Arabic_alef", '\u{627}'),
+    ("Arabic_alefmaksura", '\u{649}'),
+    ("Arabic_beh", '\u{628}'),
+    ("Arabic_comma", '\u{60c}'),
+    ("Arabic_dad", '\u{636}'),
+    ("Arabic_dal", '\u{62f}'),
+    ("Arabic_damma", '\u{64f}'),
+    ("Arabic_dammatan", '\u{64c}'),
+    ("Arabic_ddal", '\u{688}'),
+    ("Arabic_farsi_yeh", '\u{6cc}'),
+    ("Arabic_fatha", '\u{64e}'),
+    ("Arabic_fathatan", '\u{64b}'),
+    ("Arabic_feh", '\u{641}'),
+    ("Arabic_fullstop", '\u{6d4}'),
+    ("Arabic_gaf", '\u{6af}'),
+    ("Arabic_ghain", '\u{63a}'),
+    ("Arabic_ha", '\u{647}'),
+    ("Arabic_hah", '\u{62d}'),
+    ("Arabic_hamza", '\u{621}'),
+    ("Arabic_hamza_above", '\u{654}'),
+    ("Arabic_hamza_below", '\u{655}'),
+    ("Arabic_hamzaonalef", '\u{623}'),
+    ("Arabic_hamzaonwaw", '\u{624}'),
+    ("Arabic_hamzaonyeh", '\u{626}'),
+    ("Arabic_hamzaunderalef", '\u{625}'),
+    ("Arabic_heh", '\u{647}'),
+    ("Arabic_heh_doachashmee", '\u{6be}'),
+    ("Arabic_heh_goal", '\u{6c1}'),
+    ("Arabic_jeem", '\u{62c}'),
+    ("Arabic_jeh", '\u{698}'),
+    ("Arabic_kaf", '\u{643}'),
+    ("Arabic_kasra", '\u{650}'),
+    ("Arabic_kasratan", '\u{64d}'),
+    ("Arabic_keheh", '\u{6a9}'),
+    ("Arabic_khah", '\u{62e}'),
+    ("Arabic_lam", '\u{644}'),
+    ("Arabic_madda_above", '\u{653}'),
+    ("Arabic_maddaonalef", '\u{622}'),
+    ("Arabic_meem", '\u{645}'),
+    ("Arabic_noon", '\u{646}'),
+    ("Arabic_noon_ghunna", '\u{6ba}'),
+    ("Arabic_peh", '\u{67e}'),
+    ("Arabic_percent", '\u{66a}'),
+    ("Arabic_qaf", '\u{642}'),
+    ("Arabic_question_mark", '\u{61f}'),
+    ("Arabic_ra", '\u{631}'),
+    ("Arabic_rreh", '\u{691}'),
+    ("Arabic_sad", '\u{635}'),
+    ("Arabic_seen", '\u{633}'),
+    ("Arabic_semicolon", '\u{61b}'),
+    ("Arabic_shadda", '\u{651}'),
+    ("Arabic_sheen", '\u{634}'),
+    ("Arabic_sukun", '\u{652}'),
+    ("Arabic_superscript_alef", '\u{670}'),
+    ("Arabic_tah", '\u{637}'),
+    ("Arabic_tatweel", '\u{640}'),
+    ("Arabic_tcheh", '\u{686}'),
+    ("Arabic_teh", '\u{62a}'),
+    ("Arabic_tehmarbuta", '\u{629}'),
+    ("Arabic_thal", '\u{630}'),
+    ("Arabic_theh", '\u{62b}'),
+    ("Arabic_tteh", '\u{679}'),
+    ("Arabic_veh", '\u{6a4}'),
+    ("Arabic_waw", '\u{648}'),
+    ("Arabic_yeh", '\u{64a}'),
+    ("Arabic_yeh_baree", '\u{6d2}'),
+    ("Arabic_zah", '\u{638}'),
+    ("Arabic_zain", '\u{632}'),
+    ("Aring", '\u{c5}'),
+    ("Armenian_AT", '\u{538}'),
+    ("Armenian_AYB", '\u{531}'),
+    ("Armenian_BEN", '\u{532}'),
+    ("Armenian_CHA", '\u{549}'),
+    ("Armenian_DA", '\u{534}'),
+    ("Armenian_DZA", '\u{541}'),
+    ("Armenian_E", '\u{537}'),
+    ("Armenian_FE", '\u{556}'),
+    ("Armenian_GHAT", '\u{542}'),
+    ("Armenian_GIM", '\u{533}'),
+    ("Armenian_HI", '\u{545}'),
+    ("Armenian_HO", '\u{540}'),
+    ("Armenian_INI", '\u{53b}'),
+    ("Armenian_JE", '\u{54b}'),
+    ("Armenian_KE", '\u{554}'),
+    ("Armenian_KEN", '\u{53f}'),
+    ("Armenian_KHE", '\u{53d}'),
+    ("Armenian_LYUN", '\u{53c}'),
+    ("Armenian_MEN", '\u{544}'),
+    ("Armenian_NU", '\u{546}'),
+    ("Armenian_O", '\u{555}'),
+    ("Armenian_PE", '\u{54a}'),
+    ("Armenian_PYUR", '\u{553}'),
+    ("Armenian_RA", '\u{54c}'),
+    ("Armenian_RE", '\u{550}'),
+    ("Armenian_SE", '\u{54d}'),
+    ("Armenian_SHA", '\u{547}'),
+    ("Armenian_TCHE", '\u{543}'),
+    ("Armenian_TO", '\u{539}'),
+    ("Armenian_TSA", '\u{53e}'),
+    ("Armenian_TSO", '\u{551}'),
+    ("Armenian_TYUN", '\u{54f}'),
+    ("Armenian_VEV", '\u{54e}'),
+    ("Armenian_VO", '\u{548}'),
+    ("Armenian_VYUN", '\u{552}'),
+    ("Armenian_YECH", '\u{535}'),
+    ("Armenian_ZA", '\u{536}'),
+    ("Armenian_ZHE", '\u{53a}'),
+    ("Armenian_accent", '\u{55b}'),
+    ("Armenian_amanak", '\u{55c}'),
+    ("Armenian_apostrophe", '\u{55a}'),
+    ("Armenian_at", '\u{568}'),
+    ("Armenian_ayb", '\u{561}'),
+    ("Armenian_ben", '\u{562}'),
+    ("Armenian_but", '\u{55d}'),
+    ("Armenian_cha", '\u{579}'),
+    ("Armenian_da", '\u{564}'),
+    ("Armenian_dza", '\u{571}'),
+    ("Armenian_e", '\u{567}'),
+    ("Armenian_exclam", '\u{55c}'),
+    ("Armenian_fe", '\u{586}'),
+    ("Armenian_full_stop", '\u{589}'),
+    ("Armenian_ghat", '\u{572}'),
+    ("Armenian_gim", '\u{563}'),
+    ("Armenian_hi", '\u{575}'),
+    ("Armenian_ho", '\u{570}'),
+    ("Armenian_hyphen", '\u{58a}'),
+    ("Armenian_ini", '\u{56b}'),
+    ("Armenian_je", '\u{57b}'),
+    ("Armenian_ke", '\u{584}'),
+    ("Armenian_ken", '\u{56f}'),
+    ("Armenian_khe", '\u{56d}'),
+    ("Armenian_ligature_ew", '\u{587}'),
+    ("Armenian_lyun", '\u{56c}'),
+    ("Armenian_men", '\u{574}'),
+    ("Armenian_nu", '\u{576}'),
+    ("Armenian_o", '\u{585}'),
+    ("Armenian_paruyk", '\u{55e}'),
+    ("Armenian_pe", '\u{57a}'),
+    ("Armenian_pyur", '\u{583}'),
+    ("Armenian_question", '\u{55e}'),
+    ("Armenian_ra", '\u{57c}'),
+    ("Armenian_re", '\u{580}'),
+    ("Armenian_se", '\u{57d}'),
+    ("Armenian_separation_mark", '\u{55d}'),
+    ("Armenian_sha", '\u{577}'),
+    ("Armenian_shesht", '\u{55b}'),
+    ("Armenian_tche", '\u{573}'),
+    ("Armenian_to", '\u{569}'),
+    ("Armenian_tsa", '\u{56e}'),
+    ("Armenian_tso", '\u{581}'),
+    ("Armenian_tyun", '\u{57f}'),
+    ("Armenian_verjaket", '\u{589}'),
+    ("Armenian_vev", '\u{57e}'),
+    ("Armenian_vo", '\u{578}'),
+    ("Armenian_vyun", '\u{582}'),
+    ("Armenian_yech", '\u{565}'),
+    ("Armenian_yentamna", '\u{58a}'),
+    ("Armenian_za", '\u{566}'),
+    ("Armenian_zhe", '\u{56a}'),
+    ("Atilde", '\u{c3}'),
+    ("B", 'B'),
+    ("Babovedot", '\u{1e02}'),
+    ("Byelorussian_SHORTU", '\u{40e}'),
+    ("Byelorussian_shortu", '\u{45e}'),
+    ("C", 'C'),
+    ("Cabovedot", '\u{10a}'),
+    ("Cacute", '\u{106}'),
+    ("Ccaron", '\u{10c}'),
+    ("Ccedilla", '\u{c7}'),
+    ("Ccircumflex", '\u{108}'),
+    ("ColonSign", '\u{20a1}'),
+    ("CruzeiroSign", '\u{20a2}'),
+    ("Cyrillic_A", '\u{410}'),
+    ("Cyrillic_BE", '\u{411}'),
+    ("Cyrillic_CHE", '\u{427}'),
+    ("Cyrillic_CHE_descender", '\u{4b6}'),
+    ("Cyrillic_CHE_vertstroke", '\u{4b8}'),
+    ("Cyrillic_DE", '\u{414}'),
+    ("Cyrillic_DZHE", '\u{40f}'),
+    ("Cyrillic_E", '\u{42d}'),
+    ("Cyrillic_EF", '\u{424}'),
+    ("Cyrillic_EL", '\u{41b}'),
+    ("Cyrillic_EM", '\u{41c}'),
+    ("Cyrillic_EN", '\u{41d}'),
+    ("Cyrillic_EN_descender", '\u{4a2}'),
+    ("Cyrillic_ER", '\u{420}'),
+    ("Cyrillic_ES", '\u{421}'),
+    ("Cyrillic_GHE", '\u{413}'),
+    ("Cyrillic_GHE_bar", '\u{492}'),
+    ("Cyrillic_HA", '\u{425}'),
+    ("Cyrillic_HARDSIGN", '\u{42a}'),
+    ("Cyrillic_HA_descender", '\u{4b2}'),
+    ("Cyrillic_I", '\u{418}'),
+    ("Cyrillic_IE", '\u{415}'),
+    ("Cyrillic_IO", '\u{401}'),
+    ("Cyrillic_I_macron", '\u{4e2}'),
+    ("Cyrillic_JE", '\u{408}'),
+    ("Cyrillic_KA", '\u{41a}'),
+    ("Cyrillic_KA_descender", '\u{49a}'),
+    ("Cyrillic_KA_vertstroke", '\u{49c}'),
+    ("Cyrillic_LJE", '\u{409}'),
+    ("Cyrillic_NJE", '\u{40a}'),
+    ("Cyrillic_O", '\u{41e}'),
+    ("Cyrillic_O_bar", '\u{4e8}'),
+    ("Cyrillic_PE", '\u{41f}'),
+    ("Cyrillic_SCHWA", '\u{4d8}'),
+    ("Cyrillic_SHA", '\u{428}'),
+    ("Cyrillic_SHCHA", '\u{429}'),
+    ("Cyrillic_SHHA", '\u{4ba}'),
+    ("Cyrillic_SHORTI", '\u{419}'),
+    ("Cyrillic_SOFTSIGN", '\u{42c}'),
+    ("Cyrillic_TE", '\u{422}'),
+    ("Cyrillic_TSE", '\u{426}'),
+    ("Cyrillic_U", '\u{423}'),
+    ("Cyrillic_U_macron", '\u{4ee}'),
+    ("Cyrillic_U_straight", '\u{4ae}'),
+    ("Cyrillic_U_straight_bar", '\u{4b0}'),
+    ("Cyrillic_VE", '\u{412}'),
+    ("Cyrillic_YA", '\u{42f}'),
+    ("Cyrillic_YERU", '\u{42b}'),
+    ("Cyrillic_YU", '\u{42e}'),
+    ("Cyrillic_ZE", '\u{417}'),
+    ("Cyrillic_ZHE", '\u{416}'),
+    ("Cyrillic_ZHE_descender", '\u{496}'),
+    ("Cyrillic_a", '\u{430}'),
+    ("Cyrillic_be", '\u{431}'),
+    ("Cyrillic_che", '\u{447}'),
+    ("Cyrillic_che_descender", '\u{4b7}'),
+    ("Cyrillic_che_vertstroke", '\u{4b9}'),
+    ("Cyrillic_de", '\u{434}'),
+    ("Cyrillic_dzhe", '\u{45f}'),
+    ("Cyrillic_e", '\u{44d}'),
+    ("Cyrillic_ef", '\u{444}'),
+    ("Cyrillic_el", '\u{43b}'),
+    ("Cyrillic_em", '\u{43c}'),
+    ("Cyrillic_en", '\u{43d}'),
+    ("Cyrillic_en_descender", '\u{4a3}'),
+    ("Cyrillic_er", '\u{440}'),
+    ("Cyrillic_es", '\u{441}'),
+    ("Cyrillic_ghe", '\u{433}'),
+    ("Cyrillic_ghe_bar", '\u{493}'),
+    ("Cyrillic_ha", '\u{445}'),
+    ("Cyrillic_ha_descender", '\u{4b3}'),
+    ("Cyrillic_hardsign", '\u{44a}'),
+    ("Cyrillic_i", '\u{438}'),
+    ("Cyrillic_i_macron", '\u{4e3}'),
+    ("Cyrillic_ie", '\u{435}'),
+    ("Cyrillic_io", '\u{451}'),
+    ("Cyrillic_je", '\u{458}'),
+    ("Cyrillic_ka", '\u{43a}'),
+    ("Cyrillic_ka_descender", '\u{49b}'),
+    ("Cyrillic_ka_vertstroke", '\u{49d}'),
+    ("Cyrillic_lje", '\u{459}'),
+    ("Cyrillic_nje", '\u{45a}'),
+    ("Cyrillic_o", '\u{43e}'),
+    ("Cyrillic_o_bar", '\u{4e9}'),
+    ("Cyrillic_pe", '\u{43f}'),
+    ("Cyrillic_schwa", '\u{4d9}'),
+    ("Cyrillic_sha", '\u{448}'),
+    ("Cyrillic_shcha", '\u{449}'),
+    ("Cyrillic_shha", '\u{4bb}'),
+    ("Cyrillic_shorti", '\u{439}'),
+    ("Cyrillic_softsign", '\u{44c}'),
+    ("Cyrillic_te", '\u{442}'),
+    ("Cyrillic_tse", '\u{446}'),
+    ("Cyrillic_u", '\u{443}'),
+    ("Cyrillic_u_macron", '\u{4ef}'),
+    ("Cyrillic_u_straight", '\u{4af}'),
+    ("Cyrillic_u_straight_bar", '\u{4b1}'),
+    ("Cyrillic_ve", '\u{432}'),
+    ("Cyrillic_ya", '\u{44f}'),
+    ("Cyrillic_yeru", '\u{44b}'),
+    ("Cyrillic_yu", '\u{44e}'),
+    ("Cyrillic_ze", '\u{437}'),
+    ("Cyrillic_zhe", '\u{436}'),
+    ("Cyrillic_zhe_descender", '\u{497}'),
+    ("D", 'D'),
+    ("Dabovedot", '\u{1e0a}'),
+    ("Dcaron", '\u{10e}'),
+    ("DongSign", '\u{20ab}'),
+    ("Dstroke", '\u{110}'),
+    ("E", 'E'),
+    ("ENG", '\u{14a}'),
+    ("ETH", '\u{d0}'),
+    ("EZH", '\u{1b7}'),
+    ("Eabovedot", '\u{116}'),
+    ("Eacute", '\u{c9}'),
+    ("Ebelowdot", '\u{1eb8}'),
+    ("Ecaron", '\u{11a}'),
+    ("Ecircumflex", '\u{ca}'),
+    ("Ecircumflexacute", '\u{1ebe}'),
+    ("Ecircumflexbelowdot", '\u{1ec6}'),
+    ("Ecircumflexgrave", '\u{1ec0}'),
+    ("Ecircumflexhook", '\u{1ec2}'),
+    ("Ecircumflextilde", '\u{1ec4}'),
+    ("EcuSign", '\u{20a0}'),
+    ("Ediaeresis", '\u{cb}'),
+    ("Egrave", '\u{c8}'),
+    ("Ehook", '\u{1eba}'),
+    ("Emacron", '\u{112}'),
+    ("Eogonek", '\u{118}'),
+    ("Eth", '\u{d0}'),
+    ("Etilde", '\u{1ebc}'),
+    ("EuroSign", '\u{20ac}'),
+    ("F", 'F'),
+    ("FFrancSign", '\u{20a3}'),
+    ("Fabovedot", '\u{1e1e}'),
+    ("Farsi_0", '\u{6f0}'),
+    ("Farsi_1", '\u{6f1}'),
+    ("Farsi_2", '\u{6f2}'),
+    ("Farsi_3", '\u{6f3}'),
+    ("Farsi_4", '\u{6f4}'),
+    ("Farsi_5", '\u{6f5}'),
+    ("Farsi_6", '\u{6f6}'),
+    ("Farsi_7", '\u{6f7}'),
+    ("Farsi_8", '\u{6f8}'),
+    ("Farsi_9", '\u{6f9}'),
+    ("Farsi_yeh", '\u{6cc}'),
+    ("G", 'G'),
+    ("Gabovedot", '\u{120}'),
+    ("Gbreve", '\u{11e}'),
+    ("Gcaron", '\u{1e6}'),
+    ("Gcedilla", '\u{122}'),
+    ("Gcircumflex", '\u{11c}'),
+    ("Georgian_an", '\u{10d0}'),
+    ("Georgian_ban", '\u{10d1}'),
+    ("Georgian_can", '\u{10ea}'),
+    ("Georgian_char", '\u{10ed}'),
+    ("Georgian_chin", '\u{10e9}'),
+    ("Georgian_cil", '\u{10ec}'),
+    ("Georgian_don", '\u{10d3}'),
+    ("Georgian_en", '\u{10d4}'),
+    ("Georgian_fi", '\u{10f6}'),
+    ("Georgian_gan", '\u{10d2}'),
+    ("Georgian_ghan", '\u{10e6}'),
+    ("Georgian_hae", '\u{10f0}'),
+    ("Georgian_har", '\u{10f4}'),
+    ("Georgian_he", '\u{10f1}'),
+    ("Georgian_hie", '\u{10f2}'),
+    ("Georgian_hoe", '\u{10f5}'),
+    ("Georgian_in", '\u{10d8}'),
+    ("Georgian_jhan", '\u{10ef}'),
+    ("Georgian_jil", '\u{10eb}'),
+    ("Georgian_kan", '\u{10d9}'),
+    ("Georgian_khar", '\u{10e5}'),
+    ("Georgian_las", '\u{10da}'),
+    ("Georgian_man", '\u{10db}'),
+    ("Georgian_nar", '\u{10dc}'),
+    ("Georgian_on", '\u{10dd}'),
+    ("Georgian_par", '\u{10de}'),
+    ("Georgian_phar", '\u{10e4}'),
+    ("Georgian_qar", '\u{10e7}'),
+    ("Georgian_rae", '\u{10e0}'),
+    ("Georgian_san", '\u{10e1}'),
+    ("Georgian_shin", '\u{10e8}'),
+    ("Georgian_tan", '\u{10d7}'),
+    ("Georgian_tar", '\u{10e2}'),
+    ("Georgian_un", '\u{10e3}'),
+    ("Georgian_vin", '\u{10d5}'),
+    ("Georgian_we", '\u{10f3}'),
+    ("Georgian_xan", '\u{10ee}'),
+    ("Georgian_zen", '\u{10d6}'),
+    ("Georgian_zhar", '\u{10df}'),
+    ("Greek_ALPHA", '\u{391}'),
+    ("Greek_ALPHAaccent", '\u{386}'),
+    ("Greek_BETA", '\u{392}'),
+    ("Greek_CHI", '\u{3a7}'),
+    ("Greek_DELTA", '\u{394}'),
+    ("Greek_EPSILON", '\u{395}'),
+    ("Greek_EPSILONaccent", '\u{388}'),
+    ("Greek_ETA", '\u{397}'),
+    ("Greek_ETAaccent", '\u{389}'),
+    ("Greek_GAMMA", '\u{393}'),
+    ("Greek_IOTA", '\u{399}'),
+    ("Greek_IOTAaccent", '\u{38a}'),
+    ("Greek_IOTAdiaeresis", '\u{3aa}'),
+    ("Greek_IOTAdieresis", '\u{3aa}'),
+    ("Greek_KAPPA", '\u{39a}'),
+    ("Greek_LAMBDA", '\u{39b}'),
+    ("Greek_LAMDA", '\u{39b}'),
+    ("Greek_MU", '\u{39c}'),
+    ("Greek_NU", '\u{39d}'),
+    ("Greek_OMEGA", '\u{3a9}'),
+    ("Greek_OMEGAaccent", '\u{38f}'),
+    ("Greek_OMICRON", '\u{39f}'),
+    ("Greek_OMICRONaccent", '\u{38c}'),
+    ("Greek_PHI", '\u{3a6}'),
+    ("Greek_PI", '\u{3a0}'),
+    ("Greek_PSI", '\u{3a8}'),
+    ("Greek_RHO", '\u{3a1}'),
+    ("Greek_SIGMA", '\u{3a3}'),
+    ("Greek_TAU", '\u{3a4}'),
+    ("Greek_THETA", '\u{398}'),
+    ("Greek_UPSILON", '\u{3a5}'),
+    ("Greek_UPSILONaccent", '\u{38e}'),
+    ("Greek_UPSILONdieresis", '\u{3ab}'),
+    ("Greek_XI", '\u{39e}'),
+    ("Greek_ZETA", '\u{396}'),
+    ("Greek_accentdieresis", '\u{385}'),
+    ("Greek_alpha", '\u{3b1}'),
+    ("Greek_alphaaccent", '\u{3ac}'),
+    ("Greek_beta", '\u{3b2}'),
+    ("Greek_chi", '\u{3c7}'),
+    ("Greek_delta", '\u{3b4}'),
+    ("Greek_epsilon", '\u{3b5}'),
+    ("Greek_epsilonaccent", '\u{3ad}'),
+    ("Greek_eta", '\u{3b7}'),
+    ("Greek_etaaccent", '\u{3ae}'),
+    ("Greek_finalsmallsigma", '\u{3c2}'),
+    ("Greek_gamma", '\u{3b3}'),
+    ("Greek_horizbar", '\u{2015}'),
+    ("Greek_iota", '\u{3b9}'),
+    ("Greek_iotaaccent", '\u{3af}'),
+    ("Greek_iotaaccentdieresis", '\u{390}'),
+    ("Greek_iotadieresis", '\u{3ca}'),
+    ("Greek_kappa", '\u{3ba}'),
+    ("Greek_lambda", '\u{3bb}'),
+    ("Greek_lamda", '\u{3bb}'),
+    ("Greek_mu", '\u{3bc}'),
+    ("Greek_nu", '\u{3bd}'),
+    ("Greek_omega", '\u{3c9}'),
+    ("Greek_omegaaccent", '\u{3ce}'),
+    ("Greek_omicron", '\u{3bf}'),
+    ("Greek_omicronaccent", '\u{3cc}'),
+    ("Greek_phi", '\u{3c6}'),
+    ("Greek_pi", '\u{3c0}'),
+    ("Greek_psi", '\u{3c8}'),
+    ("Greek_rho", '\u{3c1}'),
+    ("Greek_sigma", '\u{3c3}'),
+    ("Greek_tau", '\u{3c4}'),
+    ("Greek_theta", '\u{3b8}'),
+    ("Greek_upsilon", '\u{3c5}'),
+    ("Greek_upsilonaccent", '\u{3cd}'),
+    ("Greek_upsilonaccentdieresis", '\u{3b0}'),
+    ("Greek_upsilondieresis", '\u{3cb}'),
+    ("Greek_xi", '\u{3be}'),
+    ("Greek_zeta", '\u{3b6}'),
+    ("H", 'H'),
+    ("Hangul_A", '\u{314f}'),
+    ("Hangul_AE", '\u{3150}'),
+    ("Hangul_AraeA", '\u{318d}'),
+    ("Hangul_AraeAE", '\u{318e}'),
+    ("Hangul_Cieuc", '\u{314a}'),
+    ("Hangul_Dikeud", '\u{3137}'),
+    ("Hangul_E", '\u{3154}'),
+    ("Hangul_EO", '\u{3153}'),
+    ("Hangul_EU", '\u{3161}'),
+    ("Hangul_Hieuh", '\u{314e}'),
+    ("Hangul_I", '\u{3163}'),
+    ("Hangul_Ieung", '\u{3147}'),
+    ("Hangul_J_Cieuc", '\u{11be}'),
+    ("Hangul_J_Dikeud", '\u{11ae}'),
+    ("Hangul_J_Hieuh", '\u{11c2}'),
+    ("Hangul_J_Ieung", '\u{11bc}'),
+    ("Hangul_J_Jieuj", '\u{11bd}'),
+    ("Hangul_J_Khieuq", '\u{11bf}'),
+    ("Hangul_J_Kiyeog", '\u{11a8}'),
+    ("Hangul_J_KiyeogSios", '\u{11aa}'),
+    ("Hangul_J_KkogjiDalrinIeung", '\u{11f0}'),
+    ("Hangul_J_Mieum", '\u{11b7}'),
+    ("Hangul_J_Nieun", '\u{11ab}'),
+    ("Hangul_J_NieunHieuh", '\u{11ad}'),
+    ("Hangul_J_NieunJieuj", '\u{11ac}'),
+    ("Hangul_J_PanSios", '\u{11eb}'),
+    ("Hangul_J_Phieuf", '\u{11c1}'),
+    ("Hangul_J_Pieub", '\u{11b8}'),
+    ("Hangul_J_PieubSios", '\u{11b9}'),
+    ("Hangul_J_Rieul", '\u{11af}'),
+    ("Hangul_J_RieulHieuh", '\u{11b6}'),
+    ("Hangul_J_RieulKiyeog", '\u{11b0}'),
+    ("Hangul_J_RieulMieum", '\u{11b1}'),
+    ("Hangul_J_RieulPhieuf", '\u{11b5}'),
+    ("Hangul_J_RieulPieub", '\u{11b2}'),
+    ("Hangul_J_RieulSios", '\u{11b3}'),
+    ("Hangul_J_RieulTieut", '\u{11b4}'),
+    ("Hangul_J_Sios", '\u{11ba}'),
+    ("Hangul_J_SsangKiyeog", '\u{11a9}'),
+    ("Hangul_J_SsangSios", '\u{11bb}'),
+    ("Hangul_J_Tieut", '\u{11c0}'),
+    ("Hangul_J_YeorinHieuh", '\u{11f9}'),
+    ("Hangul_Jieuj", '\u{3148}'),
+    ("Hangul_Khieuq", '\u{314b}'),
+    ("Hangul_Kiyeog", '\u{3131}'),
+    ("Hangul_KiyeogSios", '\u{3133}'),
+    ("Hangul_KkogjiDalrinIeung", '\u{3181}'),
+    ("Hangul_Mieum", '\u{3141}'),
+    ("Hangul_Nieun", '\u{3134}'),
+    ("Hangul_NieunHieuh", '\u{3136}'),
+    ("Hangul_NieunJieuj", '\u{3135}'),
+    ("Hangul_O", '\u{3157}'),
+    ("Hangul_OE", '\u{315a}'),
+    ("Hangul_PanSios", '\u{317f}'),
+    ("Hangul_Phieuf", '\u{314d}'),
+    ("Hangul_Pieub", '\u{3142}'),
+    ("Hangul_PieubSios", '\u{3144}'),
+    ("Hangul_Rieul", '\u{3139}'),
+    ("Hangul_RieulHieuh", '\u{3140}'),
+    ("Hangul_RieulKiyeog", '\u{313a}'),
+    ("Hangul_RieulMieum", '\u{313b}'),
+    ("Hangul_RieulPhieuf", '\u{313f}'),
+    ("Hangul_RieulPieub", '\u{313c}'),
+    ("Hangul_RieulSios", '\u{313d}'),
+    ("Hangul_RieulTieut", '\u{313e}'),
+    ("Hangul_RieulYeorinHieuh", '\u{316d}'),
+    ("Hangul_Sios", '\u{3145}'),
+    ("Hangul_SsangDikeud", '\u{3138}'),
+    ("Hangul_SsangJieuj", '\u{3149}'),
+    ("Hangul_SsangKiyeog", '\u{3132}'),
+    ("Hangul_SsangPieub", '\u{3143}'),
+    ("Hangul_SsangSios", '\u{3146}'),
+    ("Hangul_SunkyeongeumMieum", '\u{3171}'),
+    ("Hangul_SunkyeongeumPhieuf", '\u{3184}'),
+    ("Hangul_SunkyeongeumPieub", '\u{3178}'),
+    ("Hangul_Tieut", '\u{314c}'),
+    ("Hangul_U", '\u{315c}'),
+    ("Hangul_WA", '\u{3158}'),
+    ("Hangul_WAE", '\u{3159}'),
+    ("Hangul_WE", '\u{315e}'),
+    ("Hangul_WEO", '\u{315d}'),
+    ("Hangul_WI", '\u{315f}'),
+    ("Hangul_YA", '\u{3151}'),
+    ("Hangul_YAE", '\u{3152}'),
+    ("Hangul_YE", '\u{3156}'),
+    ("Hangul_YEO", '\u{3155}'),
+    ("Hangul_YI", '\u{3162}'),
+    ("Hangul_YO", '\u{315b}'),
+    ("Hangul_YU", '\u{3160}'),
+    ("Hangul_YeorinHieuh", '\u{3186}'),
+    ("Hcircumflex", '\u{124}'),
+    ("Hstroke", '\u{126}'),
+    ("I", 'I'),
+    ("Iabovedot", '\u{130}'),
+    ("Iacute", '\u{cd}'),
+    ("Ibelowdot", '\u{1eca}'),
+    ("Ibreve", '\u{12c}'),
+    ("Icircumflex", '\u{ce}'),
+    ("Idiaeresis", '\u{cf}'),
+    ("Igrave", '\u{cc}'),
+    ("Ihook", '\u{1ec8}'),
+    ("Imacron", '\u{12a}'),
+    ("Iogonek", '\u{12e}'),
+    ("Itilde", '\u{128}'),
+    ("J", 'J'),
+    ("Jcircumflex", '\u{134}'),
+    ("K", 'K'),
+    ("Kcedilla", '\u{136}'),
+    ("Korean_Won", '\u{20a9}'),
+    ("L", 'L'),
+    ("Lacute", '\u{139}'),
+    ("Lbelowdot", '\u{1e36}'),
+    ("Lcaron", '\u{13d}'),
+    ("Lcedilla", '\u{13b}'),
+    ("LiraSign", '\u{20a4}'),
+    ("Lstroke", '\u{141}'),
+    ("M", 'M'),
+    ("Mabovedot", '\u{1e40}'),
+    ("Macedonia_DSE", '\u{405}'),
+    ("Macedonia_GJE", '\u{403}'),
+    ("Macedonia_KJE", '\u{40c}'),
+    ("Macedonia_dse", '\u{455}'),
+    ("Macedonia_gje", '\u{453}'),
+    ("Macedonia_kje", '\u{45c}'),
+    ("MillSign", '\u{20a5}'),
+    ("N", 'N'),
+    ("Nacute", '\u{143}'),
+    ("NairaSign", '\u{20a6}'),
+    ("Ncaron", '\u{147}'),
+    ("Ncedilla", '\u{145}'),
+    ("NewSheqelSign", '\u{20aa}'),
+    ("Ntilde", '\u{d1}'),
+    ("O", 'O'),
+    ("OE", '\u{152}'),
+    ("Oacute", '\u{d3}'),
+    ("Obarred", '\u{19f}'),
+    ("Obelowdot", '\u{1ecc}'),
+    ("Ocaron", '\u{1d1}'),
+    ("Ocircumflex", '\u{d4}'),
+    ("Ocircumflexacute", '\u{1ed0}'),
+    ("Ocircumflexbelowdot", '\u{1ed8}'),
+    ("Ocircumflexgrave", '\u{1ed2}'),
+    ("Ocircumflexhook", '\u{1ed4}'),
+    ("Ocircumflextilde", '\u{1ed6}'),
+    ("Odiaeresis", '\u{d6}'),
+    ("Odoubleacute", '\u{150}'),
+    ("Ograve", '\u{d2}'),
+    ("Ohook", '\u{1ece}'),
+    ("Ohorn", '\u{1a0}'),
+    ("Ohornacute", '\u{1eda}'),
+    ("Ohornbelowdot", '\u{1ee2}'),
+    ("Ohorngrave", '\u{1edc}'),
+    ("Ohornhook", '\u{1ede}'),
+    ("Ohorntilde", '\u{1ee0}'),
+    ("Omacron", '\u{14c}'),
+    ("Ooblique", '\u{d8}'),
+    ("Oslash", '\u{d8}'),
+    ("Otilde", '\u{d5}'),
+    ("P", 'P'),
+    ("Pabovedot", '\u{1e56}'),
+    ("PesetaSign", '\u{20a7}'),
+    ("Q", 'Q'),
+    ("R", 'R'),
+    ("Racute", '\u{154}'),
+    ("Rcaron", '\u{158}'),
+    ("Rcedilla", '\u{156}'),
+    ("RupeeSign", '\u{20a8}'),
+    ("S", 'S'),
+    ("SCHWA", '\u{18f}'),
+    ("Sabovedot", '\u{1e60}'),
+    ("Sacute", '\u{15a}'),
+    ("Scaron", '\u{160}'),
+    ("Scedilla", '\u{15e}'),
+    ("Scircumflex", '\u{15c}'),
+    ("Serbian_DJE", '\u{402}'),
+    ("Serbian_DZE", '\u{40f}'),
+    ("Serbian_JE", '\u{408}'),
+    ("Serbian_LJE", '\u{409}'),
+    ("Serbian_NJE", '\u{40a}'),
+    ("Serbian_TSHE", '\u{40b}'),
+    ("Serbian_dje", '\u{452}'),
+    ("Serbian_dze", '\u{45f}'),
+    ("Serbian_je", '\u{458}'),
+    ("Serbian_lje", '\u{459}'),
+    ("Serbian_nje", '\u{45a}'),
+    ("Serbian_tshe", '\u{45b}'),
+    ("Sinh_a", '\u{d85}'),
+    ("Sinh_aa", '\u{d86}'),
+    ("Sinh_aa2", '\u{dcf}'),
+    ("Sinh_ae", '\u{d87}'),
+    ("Sinh_ae2", '\u{dd0}'),
+    ("Sinh_aee", '\u{d88}'),
+    ("Sinh_aee2", '\u{dd1}'),
+    ("Sinh_ai", '\u{d93}'),
+    ("Sinh_ai2", '\u{ddb}'),
+    ("Sinh_al", '\u{dca}'),
+    ("Sinh_au", '\u{d96}'),
+    ("Sinh_au2", '\u{dde}'),
+    ("Sinh_ba", '\u{db6}'),
+    ("Sinh_bha", '\u{db7}'),
+    ("Sinh_ca", '\u{da0}'),
+    ("Sinh_cha", '\u{da1}'),
+    ("Sinh_dda", '\u{da9}'),
+    ("Sinh_ddha", '\u{daa}'),
+    ("Sinh_dha", '\u{daf}'),
+    ("Sinh_dhha", '\u{db0}'),
+    ("Sinh_e", '\u{d91}'),
+    ("Sinh_e2", '\u{dd9}'),
+    ("Sinh_ee", '\u{d92}'),
+    ("Sinh_ee2", '\u{dda}'),
+    ("Sinh_fa", '\u{dc6}'),
+    ("Sinh_ga", '\u{d9c}'),
+    ("Sinh_gha", '\u{d9d}'),
+    ("Sinh_h2", '\u{d83}'),
+    ("Sinh_ha", '\u{dc4}'),
+    ("Sinh_i", '\u{d89}'),
+    ("Sinh_i2", '\u{dd2}'),
+    ("Sinh_ii", '\u{d8a}'),
+    ("Sinh_ii2", '\u{dd3}'),
+    ("Sinh_ja", '\u{da2}'),
+    ("Sinh_jha", '\u{da3}'),
+    ("Sinh_jnya", '\u{da5}'),
+    ("Sinh_ka", '\u{d9a}'),
+    ("Sinh_kha", '\u{d9b}'),
+    ("Sinh_kunddaliya", '\u{df4}'),
+    ("Sinh_la", '\u{dbd}'),
+    ("Sinh_lla", '\u{dc5}'),
+    ("Sinh_lu", '\u{d8f}'),
+    ("Sinh_lu2", '\u{ddf}'),
+    ("Sinh_luu", '\u{d90}'),
+    ("Sinh_luu2", '\u{df3}'),
+    ("Sinh_ma", '\u{db8}'),
+    ("Sinh_mba", '\u{db9}'),
+    ("Sinh_na", '\u{db1}'),
+    ("Sinh_ndda", '\u{dac}'),
+    ("Sinh_ndha", '\u{db3}'),
+    ("Sinh_ng", '\u{d82}'),
+    ("Sinh_ng2", '\u{d9e}'),
+    ("Sinh_nga", '\u{d9f}'),
+    ("Sinh_nja", '\u{da6}'),
+    ("Sinh_nna", '\u{dab}'),
+    ("Sinh_nya", '\u{da4}'),
+    ("Sinh_o", '\u{d94}'),
+    ("Sinh_o2", '\u{ddc}'),
+    ("Sinh_oo", '\u{d95}'),
+    ("Sinh_oo2", '\u{ddd}'),
+    ("Sinh_pa", '\u{db4}'),
+    ("Sinh_pha", '\u{db5}'),
+    ("Sinh_ra", '\u{dbb}'),
+    ("Sinh_ri", '\u{d8d}'),
+    ("Sinh_rii", '\u{d8e}'),
+    ("Sinh_ru2", '\u{dd8}'),
+    ("Sinh_ruu2", '\u{df2}'),
+    ("Sinh_sa", '\u{dc3}'),
+    ("Sinh_sha", '\u{dc1}'),
+    ("Sinh_ssha", '\u{dc2}'),
+    ("Sinh_tha", '\u{dad}'),
+    ("Sinh_thha", '\u{dae}'),
+    ("Sinh_tta", '\u{da7}'),
+    ("Sinh_ttha", '\u{da8}'),
+    ("Sinh_u", '\u{d8b}'),
+    ("Sinh_u2", '\u{dd4}'),
+    ("Sinh_uu", '\u{d8c}'),
+    ("Sinh_uu2", '\u{dd6}'),
+    ("Sinh_va", '\u{dc0}'),
+    ("Sinh_ya", '\u{dba}'),
+    ("T", 'T'),
+    ("THORN", '\u{de}'),
+    ("Tabovedot", '\u{1e6a}'),
+    ("Tcaron", '\u{164}'),
+    ("Tcedilla", '\u{162}'),
+    ("Thai_baht", '\u{e3f}'),
+    ("Thai_bobaimai", '\u{e1a}'),
+    ("Thai_chochan", '\u{e08}'),
+    ("Thai_chochang", '\u{e0a}'),
+    ("Thai_choching", '\u{e09}'),
+    ("Thai_chochoe", '\u{e0c}'),
+    ("Thai_dochada", '\u{e0e}'),
+    ("Thai_dodek", '\u{e14}'),
+    ("Thai_fofa", '\u{e1d}'),
+    ("Thai_fofan", '\u{e1f}'),
+    ("Thai_hohip", '\u{e2b}'),
+    ("Thai_honokhuk", '\u{e2e}'),
+    ("Thai_khokhai", '\u{e02}'),
+    ("Thai_khokhon", '\u{e05}'),
+    ("Thai_khokhuat", '\u{e03}'),
+    ("Thai_khokhwai", '\u{e04}'),
+    ("Thai_khorakhang", '\u{e06}'),
+    ("Thai_kokai", '\u{e01}'),
+    ("Thai_lakkhangyao", '\u{e45}'),
+    ("Thai_lekchet", '\u{e57}'),
+    ("Thai_lekha", '\u{e55}'),
+    ("Thai_lekhok", '\u{e56}'),
+    ("Thai_lekkao", '\u{e59}'),
+    ("Thai_leknung", '\u{e51}'),
+    ("Thai_lekpaet", '\u{e58}'),
+    ("Thai_leksam", '\u{e53}'),
+    ("Thai_leksi", '\u{e54}'),
+    ("Thai_leksong", '\u{e52}'),
+    ("Thai_leksun", '\u{e50}'),
+    ("Thai_lochula", '\u{e2c}'),
+    ("Thai_loling", '\u{e25}'),
+    ("Thai_lu", '\u{e26}'),
+    ("Thai_maichattawa", '\u{e4b}'),
+    ("Thai_maiek", '\u{e48}'),
+    ("Thai_maihanakat", '\u{e31}'),
+    ("Thai_maitaikhu", '\u{e47}'),
+    ("Thai_maitho", '\u{e49}'),
+    ("Thai_maitri", '\u{e4a}'),
+    ("Thai_maiyamok", '\u{e46}'),
+    ("Thai_moma", '\u{e21}'),
+    ("Thai_ngongu", '\u{e07}'),
+    ("Thai_nikhahit", '\u{e4d}'),
+    ("Thai_nonen", '\u{e13}'),
+    ("Thai_nonu", '\u{e19}'),
+    ("Thai_oang", '\u{e2d}'),
+    ("Thai_paiyannoi", '\u{e2f}'),
+    ("Thai_phinthu", '\u{e3a}'),
+    ("Thai_phophan", '\u{e1e}'),
+    ("Thai_phophung", '\u{e1c}'),
+    ("Thai_phosamphao", '\u{e20}'),
+    ("Thai_popla", '\u{e1b}'),
+    ("Thai_rorua", '\u{e23}'),
+    ("Thai_ru", '\u{e24}'),
+    ("Thai_saraa", '\u{e30}'),
+    ("Thai_saraaa", '\u{e32}'),
+    ("Thai_saraae", '\u{e41}'),
+    ("Thai_saraaimaimalai", '\u{e44}'),
+    ("Thai_saraaimaimuan", '\u{e43}'),
+    ("Thai_saraam", '\u{e33}'),
+    ("Thai_sarae", '\u{e40}'),
+    ("Thai_sarai", '\u{e34}'),
+    ("Thai_saraii", '\u{e35}'),
+    ("Thai_sarao", '\u{e42}'),
+    ("Thai_sarau", '\u{e38}'),
+    ("Thai_saraue", '\u{e36}'),
+    ("Thai_sarauee", '\u{e37}'),
+    ("Thai_sarauu", '\u{e39}'),
+    ("Thai_sorusi", '\u{e29}'),
+    ("Thai_sosala", '\u{e28}'),
+    ("Thai_soso", '\u{e0b}'),
+    ("Thai_sosua", '\u{e2a}'),
+    ("Thai_thanthakhat", '\u{e4c}'),
+    ("Thai_thonangmontho", '\u{e11}'),
+    ("Thai_thophuthao", '\u{e12}'),
+    ("Thai_thothahan", '\u{e17}'),
+    ("Thai_thothan", '\u{e10}'),
+    ("Thai_thothong", '\u{e18}'),
+    ("Thai_thothung", '\u{e16}'),
+    ("Thai_topatak", '\u{e0f}'),
+    ("Thai_totao", '\u{e15}'),
+    ("Thai_wowaen", '\u{e27}'),
+    ("Thai_yoyak", '\u{e22}'),
+    ("Thai_yoying", '\u{e0d}'),
+    ("Thorn", '\u{de}'),
+    ("Tslash", '\u{166}'),
+    ("U", 'U'),
+    ("Uacute", '\u{da}'),
+    ("Ubelowdot", '\u{1ee4}'),
+    ("Ubreve", '\u{16c}'),
+    ("Ucircumflex", '\u{db}'),
+    ("Udiaeresis", '\u{dc}'),
+    ("Udoubleacute", '\u{170}'),
+    ("Ugrave", '\u{d9}'),
+    ("Uhook", '\u{1ee6}'),
+    ("Uhorn", '\u{1af}'),
+    ("Uhornacute", '\u{1ee8}'),
+    ("Uhornbelowdot", '\u{1ef0}'),
+    ("Uhorngrave", '\u{1eea}'),
+    ("Uhornhook", '\u{1eec}'),
+    ("Uhorntilde", '\u{1eee}'),
+    ("Ukrainian_GHE_WITH_UPTURN", '\u{490}'),
+    ("Ukrainian_I", '\u{406}'),
+    ("Ukrainian_IE", '\u{404}'),
+    ("Ukrainian_YI", '\u{407}'),
+    ("Ukrainian_ghe_with_upturn", '\u{491}'),
+    ("Ukrainian_i", '\u{456}'),
+    ("Ukrainian_ie", '\u{454}'),
+    ("Ukrainian_yi", '\u{457}'),
+    ("Ukranian_I", '\u{406}'),
+    ("Ukranian_JE", '\u{404}'),
+    ("Ukranian_YI", '\u{407}'),
+    ("Ukranian_i", '\u{456}'),
+    ("Ukranian_je", '\u{454}'),
+    ("Ukranian_yi", '\u{457}'),
+    ("Umacron", '\u{16a}'),
+    ("Uogonek", '\u{172}'),
+    ("Uring", '\u{16e}'),
+    ("Utilde", '\u{168}'),
+    ("V", 'V'),
+    ("W", 'W'),
+    ("Wacute", '\u{1e82}'),
+    ("Wcircumflex", '\u{174}'),
+    ("Wdiaeresis", '\u{1e84}'),
+    ("Wgrave", '\u{1e80}'),
+    ("WonSign", '\u{20a9}'),
+    ("X", 'X'),
+    ("Xabovedot", '\u{1e8a}'),
+    ("Y", 'Y'),
+    ("Yacute", '\u{dd}'),
+    ("Ybelowdot", '\u{1ef4}'),
+    ("Ycircumflex", '\u{176}'),
+    ("Ydiaeresis", '\u{178}'),
+    ("Ygrave", '\u{1ef2}'),
+    ("Yhook", '\u{1ef6}'),
+    ("Ytilde", '\u{1ef8}'),
+    ("Z", 'Z'),
+    ("Zabovedot", '\u{17b}'),
+    ("Zacute", '\u{179}'),
+    ("Zcaron", '\u{17d}'),
+    ("Zstroke", '\u{1b5}'),
+    ("a", 'a'),
+    ("aacute", '\u{e1}'),
+    ("abelowdot", '\u{1ea1}'),
+    ("abovedot", '\u{2d9}'),
+    ("abreve", '\u{103}'),
+    ("abreveacute", '\u{1eaf}'),
+    ("abrevebelowdot", '\u{1eb7}'),
+    ("abrevegrave", '\u{1eb1}'),
+    ("abrevehook", '\u{1eb3}'),
+    ("abrevetilde", '\u{1eb5}'),
+    ("acircumflex", '\u{e2}'),
+    ("acircumflexacute", '\u{1ea5}'),
+    ("acircumflexbelowdot", '\u{1ead}'),
+    ("acircumflexgrave", '\u{1ea7}'),
+    ("acircumflexhook", '\u{1ea9}'),
+    ("acircumflextilde", '\u{1eab}'),
+    ("acute", '\u{b4}'),
+    ("adiaeresis", '\u{e4}'),
+    ("ae", '\u{e6}'),
+    ("agrave", '\u{e0}'),
+    ("ahook", '\u{1ea3}'),
+    ("amacron", '\u{101}'),
+    ("ampersand", '&'),
+    ("aogonek", '\u{105}'),
+    ("apostrophe", '\''),
+    ("approxeq", '\u{2248}'),
+    ("approximate", '\u{223c}'),
+    ("aring", '\u{e5}'),
+    ("asciicircum", '^'),
+    ("asciitilde", '~'),
+    ("asterisk", '*'),
+    ("at", '@'),
+    ("atilde", '\u{e3}'),
+    ("b", 'b'),
+    ("babovedot", '\u{1e03}'),
+    ("backslash", '\\'),
+    ("ballotcross", '\u{2717}'),
+    ("bar", '|'),
+    ("because", '\u{2235}'),
+    ("botintegral", '\u{2321}'),
+    ("botleftparens", '\u{239d}'),
+    ("botleftsqbracket", '\u{23a3}'),
+    ("botrightparens", '\u{23a0}'),
+    ("botrightsqbracket", '\u{23a6}'),
+    ("bott", '\u{2534}'),
+    ("braceleft", '{'),
+    ("braceright", '}'),
+    ("bracketleft", '['),
+    ("bracketright", ']'),
+    ("braille_blank", '\u{2800}'),
+    ("braille_dots_1", '\u{2801}'),
+    ("braille_dots_12", '\u{2803}'),
+    ("braille_dots_123", '\u{2807}'),
+    ("braille_dots_1234", '\u{280}'),
+    ("braille_dots_12345", '\u{281}'),
+    ("braille_dots_123456", '\u{283}'),
+    ("braille_dots_1234567", '\u{287}'),
+    ("braille_dots_12345678", '('),
+    ("braille_dots_1234568", '('),
+    ("braille_dots_123457", '\u{285}'),
+    ("braille_dots_1234578", '('),
+    ("braille_dots_123458", '\u{289}'),
+    ("braille_dots_12346", '\u{282}'),
+    ("braille_dots_123467", '\u{286}'),
+    ("braille_dots_1234678", '('),
+    ("braille_dots_123468", '('),
+    ("braille_dots_12347", '\u{284}'),
+    ("braille_dots_123478", '('),
+    ("braille_dots_12348", '\u{288}'),
+    ("braille_dots_1235", '\u{2817}'),
+    ("braille_dots_12356", '\u{2837}'),
+    ("braille_dots_123567", '\u{2877}'),
+    ("braille_dots_1235678", '('),
+    ("braille_dots_123568", '('),
+    ("braille_dots_12357", '\u{2857}'),
+    ("braille_dots_123578", '('),
+    ("braille_dots_12358", '\u{2897}'),
+    ("braille_dots_1236", '\u{2827}'),
+    ("braille_dots_12367", '\u{2867}'),
+    ("braille_dots_123678", '('),
+    ("braille_dots_12368", '('),
+    ("braille_dots_1237", '\u{2847}'),
+    ("braille_dots_12378", '('),
+    ("braille_dots_1238", '\u{2887}'),
+    ("braille_dots_124", '\u{280}'),
+    ("braille_dots_1245", '\u{281}'),
+    ("braille_dots_12456", '\u{283}'),
+    ("braille_dots_124567", '\u{287}'),
+    ("braille_dots_1245678", '('),
+    ("braille_dots_124568", '('),
+    ("braille_dots_12457", '\u{285}'),
+    ("braille_dots_124578", '('),
+    ("braille_dots_12458", '\u{289}'),
+    ("braille_dots_1246", '\u{282}'),
+    ("braille_dots_12467", '\u{286}'),
+    ("braille_dots_124678", '('),
+    ("braille_dots_12468", '('),
+    ("braille_dots_1247", '\u{284}'),
+    ("braille_dots_12478", '('),
+    ("braille_dots_1248", '\u{288}'),
+    ("braille_dots_125", '\u{2813}'),
+    ("braille_dots_1256", '\u{2833}'),
+    ("braille_dots_12567", '\u{2873}'),
+    ("braille_dots_125678", '('),
+    ("braille_dots_12568", '('),
+    ("braille_dots_1257", '\u{2853}'),
+    ("braille_dots_12578", '('),
+    ("braille_dots_1258", '\u{2893}'),
+    ("braille_dots_126", '\u{2823}'),
+    ("braille_dots_1267", '\u{2863}'),
+    ("braille_dots_12678", '('),
+    ("braille_dots_1268", '('),
+    ("braille_dots_127", '\u{2843}'),
+    ("braille_dots_1278", '('),
+    ("braille_dots_128", '\u{2883}'),
+    ("braille_dots_13", '\u{2805}'),
+    ("braille_dots_134", '\u{280}'),
+    ("braille_dots_1345", '\u{281}'),
+    ("braille_dots_13456", '\u{283}'),
+    ("braille_dots_134567", '\u{287}'),
+    ("braille_dots_1345678", '('),
+    ("braille_dots_134568", '('),
+    ("braille_dots_13457", '\u{285}'),
+    ("braille_dots_134578", '('),
+    ("braille_dots_13458", '\u{289}'),
+    ("braille_dots_1346", '\u{282}'),
+    ("braille_dots_13467", '\u{286}'),
+    ("braille_dots_134678", '('),
+    ("braille_dots_13468", '('),
+    ("braille_dots_1347", '\u{284}'),
+    ("braille_dots_13478", '('),
+    ("braille_dots_1348", '\u{288}'),
+    ("braille_dots_135", '\u{2815}'),
+    ("braille_dots_1356", '\u{2835}'),
+    ("braille_dots_13567", '\u{2875}'),
+    ("braille_dots_135678", '('),
+    ("braille_dots_13568", '('),
+    ("braille_dots_1357", '\u{2855}'),
+    ("braille_dots_13578", '('),
+    ("braille_dots_1358", '\u{2895}'),
+    ("braille_dots_136", '\u{2825}'),
+    ("braille_dots_1367", '\u{2865}'),
+    ("braille_dots_13678", '('),
+    ("braille_dots_1368", '('),
+    ("braille_dots_137", '\u{2845}'),
+    ("braille_dots_1378", '('),
+    ("braille_dots_138", '\u{2885}'),
+    ("braille_dots_14", '\u{2809}'),
+    ("braille_dots_145", '\u{2819}'),
+    ("braille_dots_1456", '\u{2839}'),
+    ("braille_dots_14567", '\u{2879}'),
+    ("braille_dots_145678", '('),
+    ("braille_dots_14568", '('),
+    ("braille_dots_1457", '\u{2859}'),
+    ("braille_dots_14578", '('),
+    ("braille_dots_1458", '\u{2899}'),
+    ("braille_dots_146", '\u{2829}'),
+    ("braille_dots_1467", '\u{2869}'),
+    ("braille_dots_14678", '('),
+    ("braille_dots_1468", '('),
+    ("braille_dots_147", '\u{2849}'),
+    ("braille_dots_1478", '('),
+    ("braille_dots_148", '\u{2889}'),
+    ("braille_dots_15", '\u{2811}'),
+    ("braille_dots_156", '\u{2831}'),
+    ("braille_dots_1567", '\u{2871}'),
+    ("braille_dots_15678", '('),
+    ("braille_dots_1568", '('),
+    ("braille_dots_157", '\u{2851}'),
+    ("braille_dots_1578", '('),
+    ("braille_dots_158", '\u{2891}'),
+    ("braille_dots_16", '\u{2821}'),
+    ("braille_dots_167", '\u{2861}'),
+    ("braille_dots_1678", '('),
+    ("braille_dots_168", '('),
+    ("braille_dots_17", '\u{2841}'),
+    ("braille_dots_178", '('),
+    ("braille_dots_18", '\u{2881}'),
+    ("braille_dots_2", '\u{2802}'),
+    ("braille_dots_23", '\u{2806}'),
+    ("braille_dots_234", '\u{280}'),
+    ("braille_dots_2345", '\u{281}'),
+    ("braille_dots_23456", '\u{283}'),
+    ("braille_dots_234567", '\u{287}'),
+    ("braille_dots_2345678", '('),
+    ("braille_dots_234568", '('),
+    ("braille_dots_23457", '\u{285}'),
+    ("braille_dots_234578", '('),
+    ("braille_dots_23458", '\u{289}'),
+    ("braille_dots_2346", '\u{282}'),
+    ("braille_dots_23467", '\u{286}'),
+    ("braille_dots_234678", '('),
+    ("braille_dots_23468", '('),
+    ("braille_dots_2347", '\u{284}'),
+    ("braille_dots_23478", '('),
+    ("braille_dots_2348", '\u{288}'),
+    ("braille_dots_235", '\u{2816}'),
+    ("braille_dots_2356", '\u{2836}'),
+    ("braille_dots_23567", '\u{2876}'),
+    ("braille_dots_235678", '('),
+    ("braille_dots_23568", '('),
+    ("braille_dots_2357", '\u{2856}'),
+    ("braille_dots_23578", '('),
+    ("braille_dots_2358", '\u{2896}'),
+    ("braille_dots_236", '\u{2826}'),
+    ("braille_dots_2367", '\u{2866}'),
+    ("braille_dots_23678", '('),
+    ("braille_dots_2368", '('),
+    ("braille_dots_237", '\u{2846}'),
+    ("braille_dots_2378", '('),
+    ("braille_dots_238", '\u{2886}'),
+    ("braille_dots_24", '\u{280}'),
+    ("braille_dots_245", '\u{281}'),
+    ("braille_dots_2456", '\u{283}'),
+    ("braille_dots_24567", '\u{287}'),
+    ("braille_dots_245678", '('),
+    ("braille_dots_24568", '('),
+    ("braille_dots_2457", '\u{285}'),
+    ("braille_dots_24578", '('),
+    ("braille_dots_2458", '\u{289}'),
+    ("braille_dots_246", '\u{282}'),
+    ("braille_dots_2467", '\u{286}'),
+    ("braille_dots_24678", '('),
+    ("braille_dots_2468", '('),
+    ("braille_dots_247", '\u{284}'),
+    ("braille_dots_2478", '('),
+    ("braille_dots_248", '\u{288}'),
+    ("braille_dots_25", '\u{2812}'),
+    ("braille_dots_256", '\u{2832}'),
+    ("braille_dots_2567", '\u{2872}'),
+    ("braille_dots_25678", '('),
+    ("braille_dots_2568", '('),
+    ("braille_dots_257", '\u{2852}'),
+    ("braille_dots_2578", '('),
+    ("braille_dots_258", '\u{2892}'),
+    ("braille_dots_26", '\u{2822}'),
+    ("braille_dots_267", '\u{2862}'),
+    ("braille_dots_2678", '('),
+    ("braille_dots_268", '('),
+    ("braille_dots_27", '\u{2842}'),
+    ("braille_dots_278", '('),
+    ("braille_dots_28", '\u{2882}'),
+    ("braille_dots_3", '\u{2804}'),
+    ("braille_dots_34", '\u{280}'),
+    ("braille_dots_345", '\u{281}'),
+    ("braille_dots_3456", '\u{283}'),
+    ("braille_dots_34567", '\u{287}'),
+    ("braille_dots_345678", '('),
+    ("braille_dots_34568", '('),
+    ("braille_dots_3457", '\u{285}'),
+    ("braille_dots_34578", '('),
+    ("braille_dots_3458", '\u{289}'),
+    ("braille_dots_346", '\u{282}'),
+    ("braille_dots_3467", '\u{286}'),
+    ("braille_dots_34678", '('),
+    ("braille_dots_3468", '('),
+    ("braille_dots_347", '\u{284}'),
+    ("braille_dots_3478", '('),
+    ("braille_dots_348", '\u{288}'),
+    ("braille_dots_35", '\u{2814}'),
+    ("braille_dots_356", '\u{2834}'),
+    ("braille_dots_3567", '\u{2874}'),
+    ("braille_dots_35678", '('),
+    ("braille_dots_3568", '('),
+    ("braille_dots_357", '\u{2854}'),
+    ("braille_dots_3578", '('),
+    ("braille_dots_358", '\u{2894}'),
+    ("braille_dots_36", '\u{2824}'),
+    ("braille_dots_367", '\u{2864}'),
+    ("braille_dots_3678", '('),
+    ("braille_dots_368", '('),
+    ("braille_dots_37", '\u{2844}'),
+    ("braille_dots_378", '('),
+    ("braille_dots_38", '\u{2884}'),
+    ("braille_dots_4", '\u{2808}'),
+    ("braille_dots_45", '\u{2818}'),
+    ("braille_dots_456", '\u{2838}'),
+    ("braille_dots_4567", '\u{2878}'),
+    ("braille_dots_45678", '('),
+    ("braille_dots_4568", '('),
+    ("braille_dots_457", '\u{2858}'),
+    ("braille_dots_4578", '('),
+    ("braille_dots_458", '\u{2898}'),
+    ("braille_dots_46", '\u{2828}'),
+    ("braille_dots_467", '\u{2868}'),
+    ("braille_dots_4678", '('),
+    ("braille_dots_468", '('),
+    ("braille_dots_47", '\u{2848}'),
+    ("braille_dots_478", '('),
+    ("braille_dots_48", '\u{2888}'),
+    ("braille_dots_5", '\u{2810}'),
+    ("braille_dots_56", '\u{2830}'),
+    ("braille_dots_567", '\u{2870}'),
+    ("braille_dots_5678", '('),
+    ("braille_dots_568", '('),
+    ("braille_dots_57", '\u{2850}'),
+    ("braille_dots_578", '('),
+    ("braille_dots_58", '\u{2890}'),
+    ("braille_dots_6", '\u{2820}'),
+    ("braille_dots_67", '\u{2860}'),
+    ("braille_dots_678", '('),
+    ("braille_dots_68", '('),
+    ("braille_dots_7", '\u{2840}'),
+    ("braille_dots_78", '('),
+    ("braille_dots_8", '\u{2880}'),
+    ("breve", '\u{2d8}'),
+    ("brokenbar", '\u{a6}'),
+    ("c", 'c'),
+    ("cabovedot", '\u{10b}'),
+    ("cacute", '\u{107}'),
+    ("careof", '\u{2105}'),
+    ("caret", '\u{2038}'),
+    ("caron", '\u{2c7}'),
+    ("ccaron", '\u{10d}'),
+    ("ccedilla", '\u{e7}'),
+    ("ccircumflex", '\u{109}'),
+    ("cedilla", '\u{b8}'),
+    ("cent", '\u{a2}'),
+    ("checkerboard", '\u{2592}'),
+    ("checkmark", '\u{2713}'),
+    ("circle", '\u{25cb}'),
+    ("club", '\u{2663}'),
+    ("colon", ':'),
+    ("combining_acute", '\u{301}'),
+    ("combining_belowdot", '\u{323}'),
+    ("combining_grave", '\u{300}'),
+    ("combining_hook", '\u{309}'),
+    ("combining_tilde", '\u{303}'),
+    ("comma", ','),
+    ("containsas", '\u{220b}'),
+    ("copyright", '\u{a9}'),
+    ("cr", '\u{240d}'),
+    ("crossinglines", '\u{253c}'),
+    ("cuberoot", '\u{221b}'),
+    ("currency", '\u{a4}'),
+    ("d", 'd'),
+    ("dabovedot", '\u{1e0b}'),
+    ("dagger", '\u{2020}'),
+    ("dcaron", '\u{10f}'),
+    ("decimalpoint", '.'),
+    ("degree", '\u{b0}'),
+    ("diaeresis", '\u{a8}'),
+    ("diamond", '\u{2666}'),
+    ("digitspace", '\u{2007}'),
+    ("dintegral", '\u{222c}'),
+    ("division", '\u{f7}'),
+    ("dollar", '$'),
+    ("doubbaselinedot", '\u{2025}'),
+    ("doubleacute", '\u{2dd}'),
+    ("doubledagger", '\u{2021}'),
+    ("doublelowquotemark", '\u{201e}'),
+    ("downarrow", '\u{2193}'),
+    ("downcaret", '\u{2228}'),
+    ("downshoe", '\u{222a}'),
+    ("downstile", '\u{230a}'),
+    ("downtack", '\u{22a4}'),
+    ("dstroke", '\u{111}'),
+    ("e", 'e'),
+    ("eabovedot", '\u{117}'),
+    ("eacute", '\u{e9}'),
+    ("ebelowdot", '\u{1eb9}'),
+    ("ecaron", '\u{11b}'),
+    ("ecircumflex", '\u{ea}'),
+    ("ecircumflexacute", '\u{1ebf}'),
+    ("ecircumflexbelowdot", '\u{1ec7}'),
+    ("ecircumflexgrave", '\u{1ec1}'),
+    ("ecircumflexhook", '\u{1ec3}'),
+    ("ecircumflextilde", '\u{1ec5}'),
+    ("ediaeresis", '\u{eb}'),
+    ("egrave", '\u{e8}'),
+    ("ehook", '\u{1ebb}'),
+    ("eightsubscript", '\u{2088}'),
+    ("eightsuperior", '\u{2078}'),
+    ("elementof", '\u{2208}'),
+    ("ellipsis", '\u{2026}'),
+    ("em3space", '\u{2004}'),
+    ("em4space", '\u{2005}'),
+    ("emacron", '\u{113}'),
+    ("emdash", '\u{2014}'),
+    ("emfilledcircle", '\u{25cf}'),
+    ("emfilledrect", '\u{25ae}'),
+    ("emopencircle", '\u{25cb}'),
+    ("emopenrectangle", '\u{25af}'),
+    ("emptyset", '\u{2205}'),
+    ("emspace", '\u{2003}'),
+    ("endash", '\u{2013}'),
+    ("enfilledcircbullet", '\u{2022}'),
+    ("enfilledsqbullet", '\u{25aa}'),
+    ("eng", '\u{14b}'),
+    ("enopencircbullet", '\u{25e6}'),
+    ("enopensquarebullet", '\u{25ab}'),
+    ("enspace", '\u{2002}'),
+    ("eogonek", '\u{119}'),
+    ("equal", '='),
+    ("eth", '\u{f0}'),
+    ("etilde", '\u{1ebd}'),
+    ("exclam", '!'),
+    ("exclamdown", '\u{a1}'),
+    ("ezh", '\u{292}'),
+    ("f", 'f'),
+    ("fabovedot", '\u{1e1f}'),
+    ("femalesymbol", '\u{2640}'),
+    ("ff", '\u{240c}'),
+    ("figdash", '\u{2012}'),
+    ("filledlefttribullet", '\u{25c0}'),
+    ("filledrectbullet", '\u{25ac}'),
+    ("filledrighttribullet", '\u{25b6}'),
+    ("filledtribulletdown", '\u{25bc}'),
+    ("filledtribulletup", '\u{25b2}'),
+    ("fiveeighths", '\u{215d}'),
+    ("fivesixths", '\u{215a}'),
+    ("fivesubscript", '\u{2085}'),
+    ("fivesuperior", '\u{2075}'),
+    ("fourfifths", '\u{2158}'),
+    ("foursubscript", '\u{2084}'),
+    ("foursuperior", '\u{2074}'),
+    ("fourthroot", '\u{221c}'),
+    ("function", '\u{192}'),
+    ("g", 'g'),
+    ("gabovedot", '\u{121}'),
+    ("gbreve", '\u{11f}'),
+    ("gcaron", '\u{1e7}'),
+    ("gcedilla", '\u{123}'),
+    ("gcircumflex", '\u{11d}'),
+    ("grave", '`'),
+    ("greater", '>'),
+    ("greaterthanequal", '\u{2265}'),
+    ("guillemotleft", '\u{ab}'),
+    ("guillemotright", '\u{bb}'),
+    ("h", 'h'),
+    ("hairspace", '\u{200a}'),
+    ("hcircumflex", '\u{125}'),
+    ("heart", '\u{2665}'),
+    ("hebrew_aleph", '\u{5d0}'),
+    ("hebrew_ayin", '\u{5e2}'),
+    ("hebrew_bet", '\u{5d1}'),
+    ("hebrew_beth", '\u{5d1}'),
+    ("hebrew_chet", '\u{5d7}'),
+    ("hebrew_dalet", '\u{5d3}'),
+    ("hebrew_daleth", '\u{5d3}'),
+    ("hebrew_doublelowline", '\u{2017}'),
+    ("hebrew_finalkaph", '\u{5da}'),
+    ("hebrew_finalmem", '\u{5dd}'),
+    ("hebrew_finalnun", '\u{5df}'),
+    ("hebrew_finalpe", '\u{5e3}'),
+    ("hebrew_finalzade", '\u{5e5}'),
+    ("hebrew_finalzadi", '\u{5e5}'),
+    ("hebrew_gimel", '\u{5d2}'),
+    ("hebrew_gimmel", '\u{5d2}'),
+    ("hebrew_he", '\u{5d4}'),
+    ("hebrew_het", '\u{5d7}'),
+    ("hebrew_kaph", '\u{5db}'),
+    ("hebrew_kuf", '\u{5e7}'),
+    ("hebrew_lamed", '\u{5dc}'),
+    ("hebrew_mem", '\u{5de}'),
+    ("hebrew_nun", '\u{5e0}'),
+    ("hebrew_pe", '\u{5e4}'),
+    ("hebrew_qoph", '\u{5e7}'),
+    ("hebrew_resh", '\u{5e8}'),
+    ("hebrew_samech", '\u{5e1}'),
+    ("hebrew_samekh", '\u{5e1}'),
+    ("hebrew_shin", '\u{5e9}'),
+    ("hebrew_taf", '\u{5ea}'),
+    ("hebrew_taw", '\u{5ea}'),
+    ("hebrew_tet", '\u{5d8}'),
+    ("hebrew_teth", '\u{5d8}'),
+    ("hebrew_waw", '\u{5d5}'),
+    ("hebrew_yod", '\u{5d9}'),
+    ("hebrew_zade", '\u{5e6}'),
+    ("hebrew_zadi", '\u{5e6}'),
+    ("hebrew_zain", '\u{5d6}'),
+    ("hebrew_zayin", '\u{5d6}'),
+    ("horizconnector", '\u{2500}'),
+    ("horizlinescan1", '\u{23ba}'),
+    ("horizlinescan3", '\u{23bb}'),
+    ("horizlinescan5", '\u{2500}'),
+    ("horizlinescan7", '\u{23bc}'),
+    ("horizlinescan9", '\u{23bd}'),
+    ("hstroke", '\u{127}'),
+    ("ht", '\u{2409}'),
+    ("hyphen", '\u{ad}'),
+    ("i", 'i'),
+    ("iacute", '\u{ed}'),
+    ("ibelowdot", '\u{1ecb}'),
+    ("ibreve", '\u{12d}'),
+    ("icircumflex", '\u{ee}'),
+    ("identical", '\u{2261}'),
+    ("idiaeresis", '\u{ef}'),
+    ("idotless", '\u{131}'),
+    ("ifonlyif", '\u{21d4}'),
+    ("igrave", '\u{ec}'),
+    ("ihook", '\u{1ec9}'),
+    ("imacron", '\u{12b}'),
+    ("implies", '\u{21d2}'),
+    ("includedin", '\u{2282}'),
+    ("includes", '\u{2283}'),
+    ("infinity", '\u{221e}'),
+    ("integral", '\u{222b}'),
+    ("intersection", '\u{2229}'),
+    ("iogonek", '\u{12f}'),
+    ("itilde", '\u{129}'),
+    ("j", 'j'),
+    ("jcircumflex", '\u{135}'),
+    ("jot", '\u{2218}'),
+    ("k", 'k'),
+    ("kana_A", '\u{30a2}'),
+    ("kana_CHI", '\u{30c1}'),
+    ("kana_E", '\u{30a8}'),
+    ("kana_FU", '\u{30d5}'),
+    ("kana_HA", '\u{30cf}'),
+    ("kana_HE", '\u{30d8}'),
+    ("kana_HI", '\u{30d2}'),
+    ("kana_HO", '\u{30db}'),
+    ("kana_HU", '\u{30d5}'),
+    ("kana_I", '\u{30a4}'),
+    ("kana_KA", '\u{30ab}'),
+    ("kana_KE", '\u{30b1}'),
+    ("kana_KI", '\u{30ad}'),
+    ("kana_KO", '\u{30b3}'),
+    ("kana_KU", '\u{30af}'),
+    ("kana_MA", '\u{30de}'),
+    ("kana_ME", '\u{30e1}'),
+    ("kana_MI", '\u{30df}'),
+    ("kana_MO", '\u{30e2}'),
+    ("kana_MU", '\u{30e0}'),
+    ("kana_N", '\u{30f3}'),
+    ("kana_NA", '\u{30ca}'),
+    ("kana_NE", '\u{30cd}'),
+    ("kana_NI", '\u{30cb}'),
+    ("kana_NO", '\u{30ce}'),
+    ("kana_NU", '\u{30cc}'),
+    ("kana_O", '\u{30aa}'),
+    ("kana_RA", '\u{30e9}'),
+    ("kana_RE", '\u{30ec}'),
+    ("kana_RI", '\u{30ea}'),
+    ("kana_RO", '\u{30ed}'),
+    ("kana_RU", '\u{30eb}'),
+    ("kana_SA", '\u{30b5}'),
+    ("kana_SE", '\u{30bb}'),
+    ("kana_SHI", '\u{30b7}'),
+    ("kana_SO", '\u{30bd}'),
+    ("kana_SU", '\u{30b9}'),
+    ("kana_TA", '\u{30bf}'),
+    ("kana_TE", '\u{30c6}'),
+    ("kana_TI", '\u{30c1}'),
+    ("kana_TO", '\u{30c8}'),
+    ("kana_TSU", '\u{30c4}'),
+    ("kana_TU", '\u{30c4}'),
+    ("kana_U", '\u{30a6}'),
+    ("kana_WA", '\u{30ef}'),
+    ("kana_WO", '\u{30f2}'),
+    ("kana_YA", '\u{30e4}'),
+    ("kana_YO", '\u{30e8}'),
+    ("kana_YU", '\u{30e6}'),
+    ("kana_a", '\u{30a1}'),
+    ("kana_closingbracket", '\u{300d}'),
+    ("kana_comma", '\u{3001}'),
+    ("kana_conjunctive", '\u{30fb}'),
+    ("kana_e", '\u{30a7}'),
+    ("kana_fullstop", '\u{3002}'),
+    ("kana_i", '\u{30a3}'),
+    ("kana_middledot", '\u{30fb}'),
+    ("kana_o", '\u{30a9}'),
+    ("kana_openingbracket", '\u{300c}'),
+    ("kana_tsu", '\u{30c3}'),
+    ("kana_tu", '\u{30c3}'),
+    ("kana_u", '\u{30a5}'),
+    ("kana_ya", '\u{30e3}'),
+    ("kana_yo", '\u{30e7}'),
+    ("kana_yu", '\u{30e5}'),
+    ("kappa", '\u{138}'),
+    ("kcedilla", '\u{137}'),
+    ("kra", '\u{138}'),
+    ("l", 'l'),
+    ("lacute", '\u{13a}'),
+    ("latincross", '\u{271d}'),
+    ("lbelowdot", '\u{1e37}'),
+    ("lcaron", '\u{13e}'),
+    ("lcedilla", '\u{13c}'),
+    ("leftanglebracket", '\u{2329}'),
+    ("leftarrow", '\u{2190}'),
+    ("leftcaret", '<'),
+    ("leftdoublequotemark", '\u{201c}'),
+    ("leftmiddlecurlybrace", '\u{23a8}'),
+    ("leftopentriangle", '\u{25c1}'),
+    ("leftpointer", '\u{261c}'),
+    ("leftradical", '\u{23b7}'),
+    ("leftshoe", '\u{2282}'),
+    ("leftsinglequotemark", '\u{2018}'),
+    ("leftt", '\u{251c}'),
+    ("lefttack", '\u{22a3}'),
+    ("less", '<'),
+    ("lessthanequal", '\u{2264}'),
+    ("lf", '\u{240a}'),
+    ("logicaland", '\u{2227}'),
+    ("logicalor", '\u{2228}'),
+    ("lowleftcorner", '\u{2514}'),
+    ("lowrightcorner", '\u{2518}'),
+    ("lstroke", '\u{142}'),
+    ("m", 'm'),
+    ("mabovedot", '\u{1e41}'),
+    ("macron", '\u{af}'),
+    ("malesymbol", '\u{2642}'),
+    ("maltesecross", '\u{2720}'),
+    ("masculine", '\u{ba}'),
+    ("minus", '-'),
+    ("minutes", '\u{2032}'),
+    ("mu", '\u{b5}'),
+    ("multiply", '\u{d7}'),
+    ("musicalflat", '\u{266d}'),
+    ("musicalsharp", '\u{266f}'),
+    ("n", 'n'),
+    ("nabla", '\u{2207}'),
+    ("nacute", '\u{144}'),
+    ("ncaron", '\u{148}'),
+    ("ncedilla", '\u{146}'),
+    ("ninesubscript", '\u{2089}'),
+    ("ninesuperior", '\u{2079}'),
+    ("nl", '\u{2424}'),
+    ("nobreakspace", '\u{a0}'),
+    ("notapproxeq", '\u{2247}'),
+    ("notelementof", '\u{2209}'),
+    ("notequal", '\u{2260}'),
+    ("notidentical", '\u{2262}'),
+    ("notsign", '\u{ac}'),
+    ("ntilde", '\u{f1}'),
+    ("numbersign", '#'),
+    ("numerosign", '\u{2116}'),
+    ("o", 'o'),
+    ("oacute", '\u{f3}'),
+    ("obarred", '\u{275}'),
+    ("obelowdot", '\u{1ecd}'),
+    ("ocaron", '\u{1d2}'),
+    ("ocircumflex", '\u{f4}'),
+    ("ocircumflexacute", '\u{1ed1}'),
+    ("ocircumflexbelowdot", '\u{1ed9}'),
+    ("ocircumflexgrave", '\u{1ed3}'),
+    ("ocircumflexhook", '\u{1ed5}'),
+    ("ocircumflextilde", '\u{1ed7}'),
+    ("odiaeresis", '\u{f6}'),
+    ("odoubleacute", '\u{151}'),
+    ("oe", '\u{153}'),
+    ("ogonek", '\u{2db}'),
+    ("ograve", '\u{f2}'),
+    ("ohook", '\u{1ecf}'),
+    ("ohorn", '\u{1a1}'),
+    ("ohornacute", '\u{1edb}'),
+    ("ohornbelowdot", '\u{1ee3}'),
+    ("ohorngrave", '\u{1edd}'),
+    ("ohornhook", '\u{1edf}'),
+    ("ohorntilde", '\u{1ee1}'),
+    ("omacron", '\u{14d}'),
+    ("oneeighth", '\u{215b}'),
+    ("onefifth", '\u{2155}'),
+    ("onehalf", '\u{bd}'),
+    ("onequarter", '\u{bc}'),
+    ("onesixth", '\u{2159}'),
+    ("onesubscript", '\u{2081}'),
+    ("onesuperior", '\u{b9}'),
+    ("onethird", '\u{2153}'),
+    ("ooblique", '\u{f8}'),
+    ("openrectbullet", '\u{25ad}'),
+    ("openstar", '\u{2606}'),
+    ("opentribulletdown", '\u{25bd}'),
+    ("opentribulletup", '\u{25b3}'),
+    ("ordfeminine", '\u{aa}'),
+    ("oslash", '\u{f8}'),
+    ("otilde", '\u{f5}'),
+    ("overbar", '\u{af}'),
+    ("overline", '\u{203e}'),
+    ("p", 'p'),
+    ("pabovedot", '\u{1e57}'),
+    ("paragraph", '\u{b6}'),
+    ("parenleft", '('),
+    ("parenright", ')'),
+    ("partdifferential", '\u{2202}'),
+    ("partialderivative", '\u{2202}'),
+    ("percent", '%'),
+    ("period", '.'),
+    ("periodcentered", '\u{b7}'),
+    ("permille", '\u{2030}'),
+    ("phonographcopyright", '\u{2117}'),
+    ("plus", '+'),
+    ("plusminus", '\u{b1}'),
+    ("prescription", '\u{211e}'),
+    ("prolongedsound", '\u{30fc}'),
+    ("punctspace", '\u{2008}'),
+    ("q", 'q'),
+    ("quad", '\u{2395}'),
+    ("question", '?'),
+    ("questiondown", '\u{bf}'),
+    ("quotedbl", '"'),
+    ("quoteleft", '`'),
+    ("quoteright", '\''),
+    ("r", 'r'),
+    ("racute", '\u{155}'),
+    ("radical", '\u{221a}'),
+    ("rcaron", '\u{159}'),
+    ("rcedilla", '\u{157}'),
+    ("registered", '\u{ae}'),
+    ("rightanglebracket", '\u{232a}'),
+    ("rightarrow", '\u{2192}'),
+    ("rightcaret", '>'),
+    ("rightdoublequotemark", '\u{201d}'),
+    ("rightmiddlecurlybrace", '\u{23ac}'),
+    ("rightopentriangle", '\u{25b7}'),
+    ("rightpointer", '\u{261e}'),
+    ("rightshoe", '\u{2283}'),
+    ("rightsinglequotemark", '\u{2019}'),
+    ("rightt", '\u{2524}'),
+    ("righttack", '\u{22a2}'),
+    ("s", 's'),
+    ("sabovedot", '\u{1e61}'),
+    ("sacute", '\u{15b}'),
+    ("scaron", '\u{161}'),
+    ("scedilla", '\u{15f}'),
+    ("schwa", '\u{259}'),
+    ("scircumflex", '\u{15d}'),
+    ("seconds", '\u{2033}'),
+    ("section", '\u{a7}'),
+    ("semicolon", ';'),
+    ("semivoicedsound", '\u{309c}'),
+    ("seveneighths", '\u{215e}'),
+    ("sevensubscript", '\u{2087}'),
+    ("sevensuperior", '\u{2077}'),
+    ("signaturemark", '\u{2613}'),
+    ("signifblank", '\u{2423}'),
+    ("similarequal", '\u{2243}'),
+    ("singlelowquotemark", '\u{201a}'),
+    ("sixsubscript", '\u{2086}'),
+    ("sixsuperior", '\u{2076}'),
+    ("slash", '/'),
+    ("soliddiamond", '\u{25c6}'),
+    ("space", ' '),
+    ("squareroot", '\u{221a}'),
+    ("ssharp", '\u{df}'),
+    ("sterling", '\u{a3}'),
+    ("stricteq", '\u{2263}'),
+    ("t", 't'),
+    ("tabovedot", '\u{1e6b}'),
+    ("tcaron", '\u{165}'),
+    ("tcedilla", '\u{163}'),
+    ("telephone", '\u{260e}'),
+    ("telephonerecorder", '\u{2315}'),
+    ("therefore", '\u{2234}'),
+    ("thinspace", '\u{2009}'),
+    ("thorn", '\u{fe}'),
+    ("threeeighths", '\u{215c}'),
+    ("threefifths", '\u{2157}'),
+    ("threequarters", '\u{be}'),
+    ("threesubscript", '\u{2083}'),
+    ("threesuperior", '\u{b3}'),
+    ("tintegral", '\u{222d}'),
+    ("topintegral", '\u{2320}'),
+    ("topleftparens", '\u{239b}'),
+    ("topleftradical", '\u{250c}'),
+    ("topleftsqbracket", '\u{23a1}'),
+    ("toprightparens", '\u{239e}'),
+    ("toprightsqbracket", '\u{23a4}'),
+    ("topt", '\u{252c}'),
+    ("trademark", '\u{2122}'),
+    ("tslash", '\u{167}'),
+    ("twofifths", '\u{2156}'),
+    ("twosubscript", '\u{2082}'),
+    ("twosuperior", '\u{b2}'),
+    ("twothirds", '\u{2154}'),
+    ("u", 'u'),
+    ("uacute", '\u{fa}'),
+    ("ubelowdot", '\u{1ee5}'),
+    ("ubreve", '\u{16d}'),
+    ("ucircumflex", '\u{fb}'),
+    ("udiaeresis", '\u{fc}'),
+    ("udoubleacute", '\u{171}'),
+    ("ugrave", '\u{f9}'),
+    ("uhook", '\u{1ee7}'),
+    ("uhorn", '\u{1b0}'),
+    ("uhornacute", '\u{1ee9}'),
+    ("uhornbelowdot", '\u{1ef1}'),
+    ("uhorngrave", '\u{1eeb}'),
+    ("uhornhook", '\u{1eed}'),
+    ("uhorntilde", '\u{1eef}'),
+    ("umacron", '\u{16b}'),
+    ("underbar", '_'),
+    ("underscore", '_'),
+    ("union", '\u{222a}'),
+    ("uogonek", '\u{173}'),
+    ("uparrow", '\u{2191}'),
+    ("upcaret", '\u{2227}'),
+    ("upleftcorner", '\u{250c}'),
+    ("uprightcorner", '\u{2510}'),
+    ("upshoe", '\u{2229}'),
+    ("upstile", '\u{2308}'),
+    ("uptack", '\u{22a5}'),
+    ("uring", '\u{16f}'),
+    ("utilde", '\u{169}'),
+    ("v", 'v'),
+    ("variation", '\u{221d}'),
+    ("vertbar", '\u{2502}'),
+    ("vertconnector", '\u{2502}'),
+    ("voicedsound", '\u{309b}'),
+    ("vt", '\u{240b}'),
+    ("w", 'w'),
+    ("wacute", '\u{1e83}'),
+    ("wcircumflex", '\u{175}'),
+    ("wdiaeresis", '\u{1e85}'),
+    ("wgrave", '\u{1e81}'),
+    ("x", 'x'),
+    ("xabovedot", '\u{1e8b}'),
+    ("y", 'y'),
+    ("yacute", '\u{fd}'),
+    ("ybelowdot", '\u{1ef5}'),
+    ("ycircumflex", '\u{177}'),
+    ("ydiaeresis", '\u{ff}'),
+    ("yen", '\u{a5}'),
+    ("ygrave", '\u{1ef3}'),
+    ("yhook", '\u{1ef7}'),
+    ("ytilde", '\u{1ef9}'),
+    ("z", 'z'),
+    ("zabovedot", '\u{17c}'),
+    ("zacute", '\u{17a}'),
+    ("zcaron", '\u{17e}'),
+    ("zerosubscript", '\u{2080}'),
+    ("zerosuperior", '\u{2070}'),
+    ("zstroke", '\u{1b6}')
+];
+
+// Keysyms known to XKB but producing no char (modifiers, functions...), sorted.
+pub static NON_CHARS: [&str; 337] = [
+    "3270_AltCursor", "3270_Attn", "3270_BackTab", "3270_ChangeScreen", "3270_Copy",
+    "3270_CursorBlink", "3270_CursorSelect", "3270_DeleteWord", "3270_Duplicate", "3270_Enter",
+    "3270_EraseEOF", "3270_EraseInput", "3270_ExSelect", "3270_FieldMark", "3270_Ident",
+    "3270_Jump", "3270_KeyClick", "3270_Left2", "3270_PA1", "3270_PA2", "3270_PA3", "3270_Play",
+    "3270_PrintScreen", "3270_Quit", "3270_Record", "3270_Reset", "3270_Right2", "3270_Rule",
+    "3270_Setup", "3270_Test", "AccessX_Enable", "AccessX_Feedback_Enable", "Alt_L", "Alt_R",
+    "Arabic_switch", "AudibleBell_Enable", "BackSpace", "Begin", "BounceKeys_Enable", "Break", "CH",
+    "C_H", "C_h", "Cancel", "Caps_Lock", "Ch", "Clear", "Codeinput", "Control_L", "Control_R",
+    "Delete", "Down", "Eisu_Shift", "Eisu_toggle", "End", "Escape", "Execute", "F1", "F10", "F11",
+    "F12", "F13", "F14", "F15", "F16", "F17", "F18", "F19", "F2", "F20", "F21", "F22", "F23", "F24",
+    "F25", "F26", "F27", "F28", "F29", "F3", "F30", "F31", "F32", "F33", "F34", "F35", "F4", "F5",
+    "F6", "F7", "F8", "F9", "Find", "First_Virtual_Screen", "Greek_switch", "Hangul",
+    "Hangul_Banja", "Hangul_Codeinput", "Hangul_End", "Hangul_Hanja", "Hangul_Jamo",
+    "Hangul_Jeonja", "Hangul_MultipleCandidate", "Hangul_PostHanja", "Hangul_PreHanja",
+    "Hangul_PreviousCandidate", "Hangul_Romaja", "Hangul_SingleCandidate", "Hangul_Special",
+    "Hangul_Start", "Hangul_switch", "Hankaku", "Hebrew_switch", "Help", "Henkan", "Henkan_Mode",
+    "Hiragana", "Hiragana_Katakana", "Home", "Hyper_L", "Hyper_R", "ISO_Center_Object",
+    "ISO_Continuous_Underline", "ISO_Discontinuous_Underline", "ISO_Emphasize", "ISO_Enter",
+    "ISO_Fast_Cursor_Down", "ISO_Fast_Cursor_Left", "ISO_Fast_Cursor_Right", "ISO_Fast_Cursor_Up",
+    "ISO_First_Group", "ISO_First_Group_Lock", "ISO_Group_Latch", "ISO_Group_Lock",
+    "ISO_Group_Shift", "ISO_Last_Group", "ISO_Last_Group_Lock", "ISO_Left_Tab", "ISO_Level2_Latch",
+    "ISO_Level3_Latch", "ISO_Level3_Lock", "ISO_Level3_Shift", "ISO_Level5_Latch",
+    "ISO_Level5_Lock", "ISO_Level5_Shift", "ISO_Lock", "ISO_Move_Line_Down", "ISO_Move_Line_Up",
+    "ISO_Next_Group", "ISO_Next_Group_Lock", "ISO_Partial_Line_Down", "ISO_Partial_Line_Up",
+    "ISO_Partial_Space_Left", "ISO_Partial_Space_Right", "ISO_Prev_Group", "ISO_Prev_Group_Lock",
+    "ISO_Release_Both_Margins", "ISO_Release_Margin_Left", "ISO_Release_Margin_Right",
+    "ISO_Set_Margin_Left", "ISO_Set_Margin_Right", "Insert", "KP_0", "KP_1", "KP_2", "KP_3", "KP_4",
+    "KP_5", "KP_6", "KP_7", "KP_8", "KP_9", "KP_Add", "KP_Begin", "KP_Decimal", "KP_Delete",
+    "KP_Divide", "KP_Down", "KP_End", "KP_Enter", "KP_Equal", "KP_F1", "KP_F2", "KP_F3", "KP_F4",
+    "KP_Home", "KP_Insert", "KP_Left", "KP_Multiply", "KP_Next", "KP_Page_Down", "KP_Page_Up",
+    "KP_Prior", "KP_Right", "KP_Separator", "KP_Space", "KP_Subtract", "KP_Tab", "KP_Up",
+    "Kana_Lock", "Kana_Shift", "Kanji", "Kanji_Bangou", "Katakana", "L1", "L10", "L2", "L3", "L4",
+    "L5", "L6", "L7", "L8", "L9", "Last_Virtual_Screen", "Left", "Linefeed", "Mae_Koho", "Massyo",
+    "Menu", "Meta_L", "Meta_R", "Mode_switch", "MouseKeys_Accel_Enable", "MouseKeys_Enable",
+    "Muhenkan", "Multi_key", "MultipleCandidate", "Next", "Next_Virtual_Screen", "Num_Lock",
+    "Overlay1_Enable", "Overlay2_Enable", "Page_Down", "Page_Up", "Pause", "Pointer_Accelerate",
+    "Pointer_Button1", "Pointer_Button2", "Pointer_Button3", "Pointer_Button4", "Pointer_Button5",
+    "Pointer_Button_Dflt", "Pointer_DblClick1", "Pointer_DblClick2", "Pointer_DblClick3",
+    "Pointer_DblClick4", "Pointer_DblClick5", "Pointer_DblClick_Dflt", "Pointer_DfltBtnNext",
+    "Pointer_DfltBtnPrev", "Pointer_Down", "Pointer_DownLeft", "Pointer_DownRight", "Pointer_Drag1",
+    "Pointer_Drag2", "Pointer_Drag3", "Pointer_Drag4", "Pointer_Drag5", "Pointer_Drag_Dflt",
+    "Pointer_EnableKeys", "Pointer_Left", "Pointer_Right", "Pointer_Up", "Pointer_UpLeft",
+    "Pointer_UpRight", "Prev_Virtual_Screen", "PreviousCandidate", "Print", "Prior", "R1", "R10",
+    "R11", "R12", "R13", "R14", "R15", "R2", "R3", "R4", "R5", "R6", "R7", "R8", "R9", "Redo",
+    "RepeatKeys_Enable", "Return", "Right", "Romaji", "Scroll_Lock", "Select", "Shift_L",
+    "Shift_Lock", "Shift_R", "SingleCandidate", "SlowKeys_Enable", "StickyKeys_Enable", "Super_L",
+    "Super_R", "Sys_Req", "Tab", "Terminate_Server", "Thai_maihanakat_maitho", "Touroku", "Undo",
+    "Up", "VoidSymbol", "Zen_Koho", "Zenkaku", "Zenkaku_Hankaku", "blank", "botleftsummation",
+    "botrightsummation", "botvertsummationconnector", "braille_dot_1", "braille_dot_10",
+    "braille_dot_2", "braille_dot_3", "braille_dot_4", "braille_dot_5", "braille_dot_6",
+    "braille_dot_7", "braille_dot_8", "braille_dot_9", "c_h", "ch", "cursor", "hexagram",
+    "kana_switch", "marker", "rightmiddlesummation", "script_switch", "topleftsummation",
+    "toprightsummation", "topvertsummationconnector", "trademarkincircle"
+];
@@ -0,0 +1,234 @@
+use std::fmt;
+
+use self::compose::DeadKeys;
+
+mod builtin;
+pub mod compose;
+mod keysyms;
+pub mod xkb;
+
+
+//---
+/// Modifier level producing a symbol from a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Level {
+    Base,
+    Shift,
+    AltGr,
+    ShiftAltGr
+}
+
+impl Level {
+    pub fn all() -> [Level; 4] {
+        [Level::Base, Level::Shift, Level::AltGr, Level::ShiftAltGr]
+    }
+
+    pub fn index(&self) -> usize {
+        match *self {
+            Level::Base => 0,
+            Level::Shift => 1,
+            Level::AltGr => 2,
+            Level::ShiftAltGr => 3
+        }
+    }
+
+    pub fn needs_shift(&self) -> bool {
+        match *self {
+            Level::Shift | Level::ShiftAltGr => true,
+            Level::Base | Level::AltGr => false
+        }
+    }
+
+    pub fn needs_altgr(&self) -> bool {
+        match *self {
+            Level::AltGr | Level::ShiftAltGr => true,
+            Level::Base | Level::Shift => false
+        }
+    }
+}
+
+
+//---
+/// What a key produces at a given level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Symbol {
+    Char(char),
+    // dead key, holding the combining mark it applies to the next char
+    Dead(char)
+}
+
+impl Symbol {
+    /// Symbol of the builtin tables, where combining marks make dead keys,
+    /// everything else being a plain char.
+    pub fn from_char(c: char) -> Symbol {
+        match c {
+            '\u{300}'..='\u{36f}' => Symbol::Dead(c),
+            _ => Symbol::Char(c)
+        }
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Symbol::Char(c) => write!(f, "{}", c),
+            // shown over a dotted circle, as usual for lone marks
+            Symbol::Dead(mark) => write!(f, "\u{25cc}{}", mark)
+        }
+    }
+}
+
+
+//---
+/// A key, named after its XKB key code (e.g. `AC01` for the first key
+/// of the home row), and its symbols for each level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Key {
+    pub code: String,
+    pub symbols: [Option<Symbol>; 4]
+}
+
+impl Key {
+    pub fn symbol(&self, level: Level) -> Option<Symbol> {
+        self.symbols[level.index()]
+    }
+}
+
+
+//---
+/// Key and level to type a symbol.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub code: String,
+    pub level: Level
+}
+
+
+//---
+/// Language layout: which key and modifiers produce which symbol.
+#[derive(Debug, Clone)]
+pub struct Layout {
+    pub name: String,
+    keys: Vec<Key>
+}
+
+impl Layout {
+    pub fn new(name: &str, keys: Vec<Key>) -> Layout {
+        Layout { name: name.to_string(), keys }
+    }
+
+    /// Names of the layouts shipped with the application.
+    pub fn builtin_names() -> Vec<&'static str> {
+        builtin::LAYOUTS.iter().map(|&(name, _)| name).collect()
+    }
+
+    pub fn builtin(name: &str) -> Option<Layout> {
+        let name = match name {
+            "bépo" => "bepo",
+            name => name
+        };
+        builtin::LAYOUTS.iter()
+            .find(|&&(n, _)| n == name)
+            .map(|&(n, table)| Layout::new(n, builtin::keys(table)))
+    }
+
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    pub fn key(&self, code: &str) -> Option<&Key> {
+        self.keys.iter().find(|k| k.code == code)
+    }
+
+    /// Find how to type a char, with the fewest modifiers.
+    pub fn locate(&self, c: char) -> Option<Stroke> {
+        self.locate_symbol(Symbol::Char(c))
+    }
+
+    /// Marks of the dead keys, at any level.
+    pub fn dead_keys(&self) -> DeadKeys {
+        let mut marks = Vec::new();
+        for key in self.keys.iter() {
+            for symbol in key.symbols.iter() {
+                if let Some(Symbol::Dead(mark)) = *symbol {
+                    if !marks.contains(&mark) {
+                        marks.push(mark);
+                    }
+                }
+            }
+        }
+        DeadKeys::new(marks)
+    }
+
+    pub fn locate_symbol(&self, symbol: Symbol) -> Option<Stroke> {
+        for level in Level::all().iter() {
+            for key in self.keys.iter() {
+                if key.symbol(*level) == Some(symbol) {
+                    return Some(Stroke { code: key.code.clone(), level: *level });
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stroke(code: &str, level: Level) -> Option<Stroke> {
+        Some(Stroke { code: code.to_string(), level })
+    }
+
+    #[test]
+    fn all_builtins_exist() {
+        for name in Layout::builtin_names() {
+            let layout = Layout::builtin(name).expect("missing builtin");
+            assert_eq!(layout.name, name);
+            assert_eq!(layout.locate(' '), stroke("SPCE", Level::Base));
+        }
+        assert!(Layout::builtin("bépo").is_some());
+        assert!(Layout::builtin("unknown").is_none());
+    }
+
+    #[test]
+    fn same_char_on_different_keys() {
+        let qwerty = Layout::builtin("qwerty-us").unwrap();
+        let azerty = Layout::builtin("azerty-fr").unwrap();
+        let dvorak = Layout::builtin("dvorak").unwrap();
+        let colemak = Layout::builtin("colemak").unwrap();
+
+        assert_eq!(qwerty.locate('a'), stroke("AC01", Level::Base));
+        assert_eq!(azerty.locate('a'), stroke("AD01", Level::Base));
+        assert_eq!(dvorak.locate('e'), stroke("AC03", Level::Base));
+        assert_eq!(colemak.locate('t'), stroke("AC04", Level::Base));
+    }
+
+    #[test]
+    fn modifier_levels() {
+        let azerty = Layout::builtin("azerty-fr").unwrap();
+        let bepo = Layout::builtin("bepo").unwrap();
+
+        assert_eq!(azerty.locate('1'), stroke("AE01", Level::Shift));
+        assert_eq!(azerty.locate('€'), stroke("AD03", Level::AltGr));
+        assert_eq!(bepo.locate('É'), stroke("AD02", Level::Shift));
+        assert_eq!(bepo.locate('œ'), stroke("AD04", Level::AltGr));
+        assert_eq!(bepo.locate('Œ'), stroke("AD04", Level::ShiftAltGr));
+        assert!(Level::ShiftAltGr.needs_shift() && Level::ShiftAltGr.needs_altgr());
+    }
+
+    #[test]
+    fn dead_keys() {
+        let bepo = Layout::builtin("bepo").unwrap();
+
+        assert_eq!(bepo.key("AD06").unwrap().symbol(Level::Base), Some(Symbol::Dead('\u{302}')));
+        assert_eq!(bepo.locate_symbol(Symbol::Dead('\u{308}')), stroke("AC03", Level::AltGr));
+        assert_eq!(bepo.locate('ê'), stroke("LSGT", Level::Base));
+        assert_eq!(bepo.locate('ë'), None);
+
+        let dead_keys = bepo.dead_keys();
+        assert_eq!(dead_keys.decompose('ë'), Some(('\u{308}', 'e')));
+        assert_eq!(dead_keys.decompose('ǎ'), Some(('\u{30c}', 'a')));
+        assert_eq!(Layout::builtin("qwerty-us").unwrap().dead_keys(), DeadKeys::default());
+    }
+}
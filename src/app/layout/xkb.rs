@@ -0,0 +1,473 @@
+use std::error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use app::layout::{Key, Layout, Symbol};
+use app::layout::keysyms;
+
+
+//---
+#[derive(Debug, PartialEq)]
+pub struct XkbError {
+    // line number (1-based) of the failure, 0 if the file cannot be read
+    pub line: usize,
+    // the symbol, or token, which failed
+    pub symbol: String,
+    pub reason: String
+}
+
+impl XkbError {
+    fn new(line: usize, symbol: &str, reason: &str) -> XkbError {
+        XkbError { line, symbol: symbol.to_string(), reason: reason.to_string() }
+    }
+}
+
+impl fmt::Display for XkbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {} `{}`", self.line, self.reason, self.symbol)
+    }
+}
+
+impl error::Error for XkbError {}
+
+
+/// Split the name of a layout into the path of an XKB symbols file and its
+/// variant, given between parentheses as in `symbols/fr(bepo)`.
+pub fn split_variant(name: &str) -> (&Path, Option<&str>) {
+    match name.rfind('(') {
+        Some(i) if name.ends_with(')') => (Path::new(&name[..i]), Some(&name[i + 1..name.len() - 1])),
+        _ => (Path::new(name), None)
+    }
+}
+
+/// Load a layout from an XKB symbols file, see `parse`.
+pub fn load(path: &Path, variant: Option<&str>) -> Result<Layout, XkbError> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| XkbError::new(0, &path.to_string_lossy(), &err.to_string()))?;
+    parse(&text, variant)
+}
+
+
+/// Read the `xkb_symbols` block named `variant` (or the first one) of an
+/// XKB symbols file. Only the first group of each key is kept, and
+/// `include` statements are not followed.
+pub fn parse(text: &str, variant: Option<&str>) -> Result<Layout, XkbError> {
+    let tokens = tokenize(text)?;
+    let mut parser = Parser { tokens: &tokens, at: 0 };
+
+    while !parser.is_done() {
+        if parser.peek_is("xkb_symbols") {
+            parser.next()?;
+            let name = parser.expect_string()?;
+            parser.expect("{")?;
+            if variant.is_none_or(|v| v == name) {
+                let keys = parser.symbols_body()?;
+                return Ok(Layout::new(&name, keys));
+            }
+            parser.skip_block()?;
+        } else {
+            parser.next()?;
+        }
+    }
+
+    let last_line = tokens.last().map_or(1, |t| t.line);
+    Err(XkbError::new(last_line, variant.unwrap_or("xkb_symbols"), "no such symbols block"))
+}
+
+
+//---
+#[derive(Debug, PartialEq)]
+enum Kind {
+    Ident,
+    Str,
+    // key code between angle brackets
+    Code,
+    Punct
+}
+
+#[derive(Debug)]
+struct Token {
+    kind: Kind,
+    text: String,
+    line: usize
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, XkbError> {
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => (),
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.peek().is_some_and(|c| *c != '\n') {
+                    chars.next();
+                }
+            }
+            '#' => {
+                while chars.peek().is_some_and(|c| *c != '\n') {
+                    chars.next();
+                }
+            }
+            '"' | '<' => {
+                let (end, kind) = match c {
+                    '"' => ('"', Kind::Str),
+                    _ => ('>', Kind::Code)
+                };
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some(c) if c == end => break,
+                        Some('\n') | None => return Err(XkbError::new(line, &s, "unterminated")),
+                        Some(c) => s.push(c)
+                    }
+                }
+                tokens.push(Token { kind, text: s, line });
+            }
+            '{' | '}' | '[' | ']' | '(' | ')' | ';' | ',' | '=' | '+' | '.' => {
+                tokens.push(Token { kind: Kind::Punct, text: c.to_string(), line });
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut s = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    s.push(c);
+                    chars.next();
+                }
+                tokens.push(Token { kind: Kind::Ident, text: s, line });
+            }
+            c => return Err(XkbError::new(line, &c.to_string(), "unexpected character"))
+        }
+    }
+
+    Ok(tokens)
+}
+
+
+//---
+struct Parser<'a> {
+    tokens: &'a [Token],
+    at: usize
+}
+
+impl<'a> Parser<'a> {
+    fn is_done(&self) -> bool {
+        self.at >= self.tokens.len()
+    }
+
+    fn line(&self) -> usize {
+        self.tokens.get(self.at)
+            .or_else(|| self.tokens.last())
+            .map_or(1, |t| t.line)
+    }
+
+    fn peek_is(&self, text: &str) -> bool {
+        self.tokens.get(self.at).is_some_and(|t| t.text == text && t.kind != Kind::Str)
+    }
+
+    fn next(&mut self) -> Result<&'a Token, XkbError> {
+        match self.tokens.get(self.at) {
+            Some(token) => {
+                self.at += 1;
+                Ok(token)
+            }
+            None => Err(XkbError::new(self.line(), "", "unexpected end of file"))
+        }
+    }
+
+    fn expect(&mut self, text: &str) -> Result<(), XkbError> {
+        let token = self.next()?;
+        match token.kind != Kind::Str && token.text == text {
+            true => Ok(()),
+            false => Err(XkbError::new(token.line, &token.text, &format!("expected `{}` instead of", text)))
+        }
+    }
+
+    fn expect_string(&mut self) -> Result<String, XkbError> {
+        let token = self.next()?;
+        match token.kind {
+            Kind::Str => Ok(token.text.clone()),
+            _ => Err(XkbError::new(token.line, &token.text, "expected a string instead of"))
+        }
+    }
+
+    // skip to the end of the current `{ ... };` block
+    fn skip_block(&mut self) -> Result<(), XkbError> {
+        let mut depth = 1;
+        while depth > 0 {
+            let token = self.next()?;
+            if token.kind == Kind::Punct {
+                match token.text.as_str() {
+                    "{" => depth += 1,
+                    "}" => depth -= 1,
+                    _ => ()
+                }
+            }
+        }
+        self.expect(";")
+    }
+
+    // skip to the end of the current statement
+    fn skip_statement(&mut self) -> Result<(), XkbError> {
+        let mut depth = 0;
+        loop {
+            let token = self.next()?;
+            if token.kind != Kind::Punct {
+                continue;
+            }
+            match token.text.as_str() {
+                "{" | "[" | "(" => depth += 1,
+                "}" | "]" | ")" => depth -= 1,
+                ";" if depth == 0 => return Ok(()),
+                _ => ()
+            }
+        }
+    }
+
+    fn symbols_body(&mut self) -> Result<Vec<Key>, XkbError> {
+        let mut keys: Vec<Key> = Vec::new();
+        loop {
+            if self.peek_is("}") {
+                self.next()?;
+                self.expect(";")?;
+                return Ok(keys);
+            }
+
+            let is_include = ["include", "augment", "override", "replace"].iter().any(|w| self.peek_is(w));
+            if is_include && self.tokens.get(self.at + 1).is_some_and(|t| t.kind == Kind::Str) {
+                // no semicolon after those, and the included files are not read
+                self.at += 2;
+            } else if self.peek_is("key") && self.tokens.get(self.at + 1).is_some_and(|t| t.kind == Kind::Code) {
+                self.next()?;
+                let code = self.next()?.text.clone();
+                let symbols = self.key_body()?;
+                // a later definition of the same key wins, as in XKB
+                keys.retain(|k| k.code != code);
+                keys.push(Key { code, symbols });
+            } else {
+                self.skip_statement()?;
+            }
+        }
+    }
+
+    // `{ [ a, A ] }` or `{ type = "...", symbols[Group1] = [ a, A ], ... }`
+    fn key_body(&mut self) -> Result<[Option<Symbol>; 4], XkbError> {
+        self.expect("{")?;
+        let mut symbols = None;
+        loop {
+            if self.peek_is("[") {
+                let list = self.symbol_list()?;
+                symbols = symbols.or(Some(list));
+            } else {
+                let name = self.next()?.text.clone();
+                if self.peek_is("[") {
+                    // group or level index, e.g. `symbols[Group1]`
+                    self.next()?;
+                    self.next()?;
+                    self.expect("]")?;
+                }
+                self.expect("=")?;
+                if self.peek_is("[") {
+                    match name.as_str() {
+                        "symbols" => {
+                            let list = self.symbol_list()?;
+                            symbols = symbols.or(Some(list));
+                        }
+                        _ => self.skip_list()?
+                    }
+                } else {
+                    self.next()?;
+                }
+            }
+
+            let token = self.next()?;
+            match token.text.as_str() {
+                "," => (),
+                "}" => break,
+                _ => return Err(XkbError::new(token.line, &token.text, "expected `,` or `}` instead of"))
+            }
+        }
+        self.expect(";")?;
+        Ok(symbols.unwrap_or([None; 4]))
+    }
+
+    fn skip_list(&mut self) -> Result<(), XkbError> {
+        self.expect("[")?;
+        let mut depth = 1;
+        while depth > 0 {
+            let token = self.next()?;
+            match token.text.as_str() {
+                "[" => depth += 1,
+                "]" => depth -= 1,
+                _ => ()
+            }
+        }
+        Ok(())
+    }
+
+    fn symbol_list(&mut self) -> Result<[Option<Symbol>; 4], XkbError> {
+        self.expect("[")?;
+        let mut symbols = [None; 4];
+        let mut level = 0;
+        loop {
+            let token = self.next()?;
+            if token.kind != Kind::Ident {
+                return Err(XkbError::new(token.line, &token.text, "expected a keysym instead of"));
+            }
+            let symbol = keysym(&token.text)
+                .ok_or_else(|| XkbError::new(token.line, &token.text, "unknown keysym"))?;
+            if level < symbols.len() {
+                symbols[level] = symbol;
+            }
+            level += 1;
+
+            let token = self.next()?;
+            match token.text.as_str() {
+                "," => (),
+                "]" => return Ok(symbols),
+                _ => return Err(XkbError::new(token.line, &token.text, "expected `,` or `]` instead of"))
+            }
+        }
+    }
+}
+
+
+static DEAD: [(&str, char); 16] = [
+    ("dead_grave", '\u{300}'), ("dead_acute", '\u{301}'), ("dead_circumflex", '\u{302}'),
+    ("dead_tilde", '\u{303}'), ("dead_macron", '\u{304}'), ("dead_breve", '\u{306}'),
+    ("dead_abovedot", '\u{307}'), ("dead_diaeresis", '\u{308}'), ("dead_hook", '\u{309}'),
+    ("dead_abovering", '\u{30a}'), ("dead_doubleacute", '\u{30b}'), ("dead_caron", '\u{30c}'),
+    ("dead_belowdot", '\u{323}'), ("dead_belowcomma", '\u{326}'), ("dead_cedilla", '\u{327}'),
+    ("dead_ogonek", '\u{328}')
+];
+
+
+// `Some(None)` for keysyms producing nothing, `None` for unknown ones
+fn keysym(name: &str) -> Option<Option<Symbol>> {
+    let nothing = ["NoSymbol", "VoidSymbol", "none", "any"];
+    if nothing.iter().any(|n| n.eq_ignore_ascii_case(name)) || name.starts_with("XF86") {
+        return Some(None);
+    }
+
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(Some(Symbol::Char(c)));
+    }
+
+    if let Some(&(_, mark)) = DEAD.iter().find(|&&(n, _)| n == name) {
+        return Some(Some(Symbol::Dead(mark)));
+    }
+    if name.starts_with("dead_") {
+        // dead keys without a combining mark (e.g. dead_greek) are left out
+        return Some(None);
+    }
+
+    if let Ok(i) = keysyms::CHARS.binary_search_by(|&(n, _)| n.cmp(name)) {
+        // a combining mark is typed as is, only dead_* keysyms are dead keys
+        return Some(Some(Symbol::Char(keysyms::CHARS[i].1)));
+    }
+    if keysyms::NON_CHARS.binary_search(&name).is_ok() {
+        return Some(None);
+    }
+
+    let code = if let Some(hex) = name.strip_prefix('U') {
+        u32::from_str_radix(hex, 16).ok()
+    } else if let Some(hex) = name.strip_prefix("0x") {
+        u32::from_str_radix(hex, 16).ok()
+            .filter(|v| *v >= 0x0100_0000)
+            .map(|v| v - 0x0100_0000)
+    } else {
+        None
+    };
+    code.and_then(std::char::from_u32).map(|c| Some(Symbol::Char(c)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::layout::Level;
+
+    static SAMPLE: &str = r#"
+// a bépo variant
+default partial alphanumeric_keys
+xkb_symbols "basic" {
+    include "fr(basic)"
+    name[Group1]= "French (custom)";
+
+    key <AE01> { [ quotedbl, 1, emdash, doublelowquotemark ] }; // comment
+    key <AD02> { type[group1] = "FOUR_LEVEL", [ eacute, Eacute, dead_acute, U030B ] };
+    key <AD06> { symbols[Group1] = [ dead_circumflex, exclam, exclamdown, NoSymbol ] };
+    key <AB08> { [ g, G, dead_greek, mu ] };
+    modifier_map Mod5 { <LVL3> };
+    include "level3(ralt_switch)"
+};
+
+partial alphanumeric_keys
+xkb_symbols "other" {
+    key <AC01> { [ a, A ] };
+};
+"#;
+
+    #[test]
+    fn read_first_block() {
+        let layout = parse(SAMPLE, None).expect("parsing failed");
+
+        assert_eq!(layout.name, "basic");
+        assert_eq!(layout.keys().len(), 4);
+        assert_eq!(layout.key("AE01").unwrap().symbols,
+                   [Some(Symbol::Char('"')), Some(Symbol::Char('1')), Some(Symbol::Char('—')), Some(Symbol::Char('„'))]);
+        assert_eq!(layout.key("AD02").unwrap().symbol(Level::AltGr), Some(Symbol::Dead('\u{301}')));
+        // a combining mark given by its code point isn't a dead key
+        assert_eq!(layout.key("AD02").unwrap().symbol(Level::ShiftAltGr), Some(Symbol::Char('\u{30b}')));
+        assert_eq!(layout.key("AD06").unwrap().symbol(Level::Base), Some(Symbol::Dead('\u{302}')));
+        assert_eq!(layout.key("AD06").unwrap().symbol(Level::ShiftAltGr), None);
+        assert_eq!(layout.key("AB08").unwrap().symbol(Level::AltGr), None);
+        assert_eq!(layout.locate('µ').map(|s| s.level), Some(Level::ShiftAltGr));
+    }
+
+    #[test]
+    fn read_named_block() {
+        let layout = parse(SAMPLE, Some("other")).expect("parsing failed");
+
+        assert_eq!(layout.name, "other");
+        assert_eq!(layout.locate('A').map(|s| s.code), Some("AC01".to_string()));
+        assert!(parse(SAMPLE, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn path_and_variant() {
+        assert_eq!(split_variant("symbols/fr(bepo)"), (Path::new("symbols/fr"), Some("bepo")));
+        assert_eq!(split_variant("symbols/fr"), (Path::new("symbols/fr"), None));
+        assert_eq!(split_variant("symbols/fr(bepo"), (Path::new("symbols/fr(bepo"), None));
+    }
+
+    #[test]
+    fn deprecated_keysyms() {
+        let text = "xkb_symbols \"x\" {\n    key <AC11> { [ quoteright, quotedbl, kappa, Thorn ] };\n};\n";
+        let layout = parse(text, None).expect("parsing failed");
+
+        assert_eq!(layout.key("AC11").unwrap().symbols,
+                   [Some(Symbol::Char('\'')), Some(Symbol::Char('"')), Some(Symbol::Char('ĸ')), Some(Symbol::Char('Þ'))]);
+    }
+
+    #[test]
+    fn name_failing_line_and_symbol() {
+        let text = "xkb_symbols \"x\" {\n    key <AC01> { [ a, A ] };\n    key <AC02> { [ s, notakeysym ] };\n};\n";
+
+        assert_eq!(parse(text, None).unwrap_err(),
+                   XkbError::new(3, "notakeysym", "unknown keysym"));
+    }
+
+    #[test]
+    fn name_failing_syntax() {
+        let text = "xkb_symbols \"x\" {\n    key <AC01> { [ a A ] };\n};\n";
+
+        let err = parse(text, None).unwrap_err();
+        assert_eq!((err.line, err.symbol.as_str()), (2, "A"));
+    }
+}
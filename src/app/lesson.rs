@@ -0,0 +1,345 @@
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use app::analysis::Report;
+use app::history;
+use app::layout::{Layout, Level, Symbol};
+use app::stats::Summary;
+use app::word::Bucket;
+use app::word::{adaptive, pseudo};
+use app::word::db::{Database, Query};
+
+
+// keys unlocked by each stage, the home row first, then a few keys at a
+// time going away from it, as in learn.dvorak.nl
+static STAGES: [(&str, &[&str]); 8] = [
+    ("home row", &["AC01", "AC02", "AC03", "AC04", "AC07", "AC08", "AC09", "AC10"]),
+    ("home row, inner keys", &["AC05", "AC06"]),
+    ("top row, index and middle", &["AD03", "AD04", "AD07", "AD08"]),
+    ("top row, ring and pinky", &["AD01", "AD02", "AD09", "AD10"]),
+    ("top row, inner keys", &["AD05", "AD06"]),
+    ("bottom row, index and middle", &["AB03", "AB04", "AB07", "AB08"]),
+    ("bottom row, other keys", &["AB01", "AB02", "AB05", "AB06", "AB09", "AB10"]),
+    ("remaining keys", &["AC11", "AD11", "AD12", "BKSL", "LSGT"])
+];
+
+// language of the words of the layouts, english for the others
+static LANGUAGES: [(&str, &str); 2] = [
+    ("azerty-fr", "fr"),
+    ("bepo", "fr")
+];
+
+// accuracy to pass any stage
+static MIN_ACCURACY: f64 = 0.95;
+
+// net speed to pass the first stage, then a bit more for each stage
+static MIN_WPM: f64 = 10.0;
+static WPM_STEP: f64 = 2.0;
+
+// length of the pseudo-words, short enough for the first stages
+static PSEUDO_MIN_LEN: usize = 2;
+static PSEUDO_MAX_LEN: usize = 6;
+
+// version written in new files, and the newest one understood
+pub static VERSION: u32 = 1;
+
+
+//---
+#[derive(Debug)]
+pub enum ProgressError {
+    Io(io::Error),
+    // line number (1-based) which cannot be understood
+    Malformed(usize),
+    // version of a file written by a newer release
+    UnsupportedVersion(u32)
+}
+
+impl fmt::Display for ProgressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ProgressError::Io(ref err) => write!(f, "cannot access lessons progress: {}", err),
+            ProgressError::Malformed(line) => write!(f, "lessons progress is malformed at line {}", line),
+            ProgressError::UnsupportedVersion(version) =>
+                write!(f, "lessons progress version {} is not supported", version)
+        }
+    }
+}
+
+impl error::Error for ProgressError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ProgressError::Io(ref err) => Some(err),
+            ProgressError::Malformed(_) | ProgressError::UnsupportedVersion(_) => None
+        }
+    }
+}
+
+impl From<io::Error> for ProgressError {
+    fn from(err: io::Error) -> ProgressError {
+        ProgressError::Io(err)
+    }
+}
+
+
+//---
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stage {
+    pub name: String,
+    // every char allowed in the stage, the ones of the previous stages included
+    pub chars: String,
+    // ratio, from 0 to 1
+    pub min_accuracy: f64,
+    pub min_wpm: f64
+}
+
+impl Stage {
+    pub fn is_passed(&self, summary: &Summary) -> bool {
+        summary.accuracy >= self.min_accuracy && summary.net_wpm >= self.min_wpm
+    }
+}
+
+
+//---
+/// Ordered stages to learn a layout.
+#[derive(Debug, Clone)]
+pub struct Plan {
+    pub layout: String,
+    pub language: String,
+    pub stages: Vec<Stage>
+}
+
+impl Plan {
+    /// Stages unlocking the base chars of the layout keys, skipping the
+    /// stages which would add nothing.
+    pub fn for_layout(layout: &Layout) -> Plan {
+        let mut stages = Vec::new();
+        let mut chars = String::new();
+
+        for &(name, codes) in STAGES.iter() {
+            let added: Vec<char> = codes.iter()
+                .filter_map(|code| layout.key(code))
+                .filter_map(|key| match key.symbol(Level::Base) {
+                    Some(Symbol::Char(c)) if !chars.contains(c) => Some(c),
+                    _ => None
+                })
+                .collect();
+            if added.is_empty() {
+                continue;
+            }
+
+            chars.extend(added);
+            let min_wpm = MIN_WPM + WPM_STEP * stages.len() as f64;
+            stages.push(Stage { name: name.to_string(), chars: chars.clone(), min_accuracy: MIN_ACCURACY, min_wpm });
+        }
+
+        let language = LANGUAGES.iter()
+            .find(|&&(name, _)| name == layout.name)
+            .map_or("en", |&(_, language)| language);
+        Plan { layout: layout.name.clone(), language: language.to_string(), stages }
+    }
+
+    /// Words made only of the chars of the stage, favouring the weakest
+    /// ones, completed with pseudo-words when there aren't enough.
+    pub fn bucket(&self, stage: usize, db: &Database, report: &Report, count: usize, seed: u64) -> Bucket {
+        let chars = &self.stages[stage].chars;
+        let query = Query::new().language(&self.language).only_chars(chars);
+        let mut bucket = adaptive::bucket(db, &query, report, count, seed);
+        if bucket.words.len() >= count {
+            return bucket;
+        }
+
+        let corpus = Query::new().language(&self.language);
+        let generator = pseudo::new(db.query(&corpus).iter().map(|e| e.word.as_str()), chars)
+            .lengths(PSEUDO_MIN_LEN, PSEUDO_MAX_LEN);
+        let known: Vec<String> = bucket.words.iter().map(|w| w.raw.clone()).collect();
+        // asking for more, in case some are real words already picked
+        let pseudo_words = generator.bucket(count + known.len(), seed).words.into_iter()
+            .filter(|w| !known.contains(&w.raw))
+            .take(count - bucket.words.len());
+        bucket.words.extend(pseudo_words);
+        bucket
+    }
+}
+
+
+//---
+/// Current stage of each plan, by layout name.
+#[derive(Debug, Default, PartialEq)]
+pub struct Progress {
+    stages: BTreeMap<String, usize>
+}
+
+/// `lessons` in the data directory, next to the history.
+pub fn default_path() -> Option<PathBuf> {
+    history::data_dir().map(|dir| dir.join("lessons"))
+}
+
+impl Progress {
+    /// Load a progress file, a missing file meaning no progress.
+    pub fn load(path: &Path) -> Result<Progress, ProgressError> {
+        match File::open(path) {
+            Ok(file) => Progress::read(BufReader::new(file)),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(err) => Err(ProgressError::Io(err))
+        }
+    }
+
+    /// Read a progress: a `@version <n>` line, then a layout name and its
+    /// current stage (0-based) on each line, separated by a tab.
+    pub fn read<R: BufRead>(input: R) -> Result<Progress, ProgressError> {
+        let mut stages = BTreeMap::new();
+        let mut version = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line?;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix("@version ") {
+                let read: u32 = directive.trim().parse().map_err(|_| ProgressError::Malformed(line_number))?;
+                if read > VERSION {
+                    return Err(ProgressError::UnsupportedVersion(read));
+                }
+                version = Some(read);
+                continue;
+            }
+
+            let mut fields = line.split('\t');
+            match (version, fields.next(), fields.next().and_then(|s| s.parse().ok())) {
+                (Some(_), Some(layout), Some(stage)) => { stages.insert(layout.to_string(), stage); }
+                _ => return Err(ProgressError::Malformed(line_number))
+            }
+        }
+
+        Ok(Progress { stages })
+    }
+
+    pub fn write(&self, output: &mut dyn Write) -> io::Result<()> {
+        writeln!(output, "@version {}", VERSION)?;
+        for (layout, stage) in self.stages.iter() {
+            writeln!(output, "{}\t{}", layout, stage)?;
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ProgressError> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = File::create(path)?;
+        self.write(&mut file)?;
+        Ok(())
+    }
+
+    /// Stage to train on, within the plan.
+    pub fn stage(&self, plan: &Plan) -> usize {
+        let stage = self.stages.get(&plan.layout).cloned().unwrap_or(0);
+        stage.min(plan.stages.len().saturating_sub(1))
+    }
+
+    /// Move to the next stage if the session passed the current one, and
+    /// tell whether it did.
+    pub fn update(&mut self, plan: &Plan, summary: &Summary) -> bool {
+        let stage = self.stage(plan);
+        let passed = plan.stages.get(stage).is_some_and(|s| s.is_passed(summary));
+        if passed && stage + 1 < plan.stages.len() {
+            self.stages.insert(plan.layout.clone(), stage + 1);
+        }
+        passed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    fn summary(accuracy: f64, net_wpm: f64) -> Summary {
+        Summary {
+            duration: Duration::from_secs(60),
+            keystrokes: 100,
+            errors: 0,
+            gross_wpm: net_wpm,
+            net_wpm,
+            accuracy,
+            missed: Vec::new(),
+            slowest_bigrams: Vec::new()
+        }
+    }
+
+    #[test]
+    fn stages_of_builtin_layouts() {
+        let qwerty = Plan::for_layout(&Layout::builtin("qwerty-us").unwrap());
+        assert_eq!(qwerty.stages[0].chars, "asdfjkl;");
+        assert_eq!(qwerty.stages[1].chars, "asdfjkl;gh");
+        // no extra key on the ISO side for qwerty
+        assert_eq!(qwerty.stages.len(), 8);
+        assert!(qwerty.stages[1].min_wpm > qwerty.stages[0].min_wpm);
+
+        let bepo = Plan::for_layout(&Layout::builtin("bepo").unwrap());
+        assert_eq!(bepo.stages[0].chars, "auietsrn");
+        assert_eq!(bepo.language, "fr");
+        assert!(bepo.stages.last().unwrap().chars.contains('ê'));
+    }
+
+    #[test]
+    fn words_of_unlocked_keys() {
+        let plan = Plan::for_layout(&Layout::builtin("qwerty-us").unwrap());
+        let db = Database::read("@language en\nsalad\nthe\nask\nfall\n".as_bytes()).unwrap();
+
+        let bucket = plan.bucket(0, &db, &Report::default(), 3, 1);
+        let mut words: Vec<&str> = bucket.words.iter().map(|w| w.raw.as_str()).collect();
+        words.sort();
+        assert_eq!(words, vec!["ask", "fall", "salad"]);
+
+        // not enough real words, completed with pseudo-words
+        let bucket = plan.bucket(0, &db, &Report::default(), 10, 1);
+        let words: Vec<&str> = bucket.words.iter().map(|w| w.raw.as_str()).collect();
+        assert_eq!(words.len(), 10);
+        assert!(words.contains(&"salad"));
+        assert!(words.iter().all(|w| w.chars().all(|c| plan.stages[0].chars.contains(c))), "{:?}", words);
+    }
+
+    #[test]
+    fn advance_on_pass() {
+        let plan = Plan::for_layout(&Layout::builtin("qwerty-us").unwrap());
+        let mut progress = Progress::default();
+
+        assert!(!progress.update(&plan, &summary(0.90, 30.0)));
+        assert!(!progress.update(&plan, &summary(0.99, 5.0)));
+        assert_eq!(progress.stage(&plan), 0);
+        assert!(progress.update(&plan, &summary(0.99, 30.0)));
+        assert_eq!(progress.stage(&plan), 1);
+
+        // the last stage is kept once passed
+        for _ in 0..20 {
+            progress.update(&plan, &summary(1.0, 100.0));
+        }
+        assert_eq!(progress.stage(&plan), plan.stages.len() - 1);
+    }
+
+    #[test]
+    fn save_and_resume() {
+        let plan = Plan::for_layout(&Layout::builtin("bepo").unwrap());
+        let mut progress = Progress::default();
+        progress.update(&plan, &summary(1.0, 100.0));
+
+        let mut text = Vec::new();
+        progress.write(&mut text).unwrap();
+        assert_eq!(String::from_utf8(text.clone()).unwrap(), "@version 1\nbepo\t1\n");
+
+        let read = Progress::read(&text[..]).expect("reading failed");
+        assert_eq!(read, progress);
+        assert_eq!(read.stage(&plan), 1);
+        assert_eq!(Progress::read("bepo\t1\n".as_bytes()).unwrap_err().to_string(),
+                   "lessons progress is malformed at line 1");
+        assert_eq!(Progress::read("@version 9\n".as_bytes()).unwrap_err().to_string(),
+                   "lessons progress version 9 is not supported");
+    }
+}
@@ -5,3 +5,30 @@ pub mod word;
 pub mod ui;
 
 pub mod exercise;
+
+pub mod session;
+
+pub mod engine;
+
+pub mod record;
+
+pub mod stats;
+
+#[allow(dead_code)]
+pub mod history;
+
+pub mod analysis;
+
+pub mod lesson;
+
+pub mod config;
+
+pub mod cli;
+
+pub mod error;
+
+#[allow(dead_code)]
+pub mod layout;
+
+#[allow(dead_code)]
+pub mod geometry;
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+
+//---
+/// What a keystroke did to the exercise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    // a char, right or wrong
+    Char,
+    // a dead key, waiting for the char it applies to
+    Dead,
+    // an erased char or dead key, or nothing to erase
+    Backspace
+}
+
+
+//---
+/// A key typed, and where it was typed: the char expected there, or after
+/// a backspace the char to type again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keystroke {
+    // time since the start of the session
+    pub at: Duration,
+    pub kind: Kind,
+    pub expected: char,
+    // the char, the dead key as sent by the terminal, or '\u{8}'
+    pub typed: char,
+    // index of the word in the bucket
+    pub word: usize,
+    // index of the char in the exercise
+    pub offset: usize
+}
+
+impl Keystroke {
+    pub fn is_correct(&self) -> bool {
+        self.kind == Kind::Char && self.expected == self.typed
+    }
+}
+
+
+//---
+/// Every keystroke of a session, in order.
+#[derive(Debug, Default)]
+pub struct Record {
+    keystrokes: Vec<Keystroke>
+}
+
+impl Record {
+    pub fn push(&mut self, keystroke: Keystroke) {
+        debug_assert!(self.keystrokes.last().is_none_or(|last| last.at <= keystroke.at),
+                      "keystrokes must be recorded in order");
+        self.keystrokes.push(keystroke);
+    }
+
+    pub fn keystrokes(&self) -> &[Keystroke] {
+        &self.keystrokes
+    }
+
+    /// Chars typed, right or wrong, without dead keys and backspaces.
+    pub fn chars(&self) -> Vec<Keystroke> {
+        self.keystrokes.iter().filter(|k| k.kind == Kind::Char).cloned().collect()
+    }
+
+    /// Time between the first and the last keystroke.
+    pub fn duration(&self) -> Duration {
+        match (self.keystrokes.first(), self.keystrokes.last()) {
+            (Some(first), Some(last)) => last.at - first.at,
+            _ => Duration::from_secs(0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn duration_between_keystrokes() {
+        use super::*;
+
+        let mut record = Record::default();
+        assert_eq!(record.duration(), Duration::from_secs(0));
+
+        let key = |ms, kind, typed| {
+            Keystroke { at: Duration::from_millis(ms), kind, expected: 'a', typed, word: 0, offset: 0 }
+        };
+        record.push(key(200, Kind::Char, 'a'));
+        record.push(key(1200, Kind::Char, 'x'));
+        record.push(key(1500, Kind::Backspace, '\u{8}'));
+
+        assert_eq!(record.keystrokes().len(), 3);
+        assert_eq!(record.chars().len(), 2);
+        assert_eq!(record.duration(), Duration::from_millis(1300));
+        assert!(record.keystrokes()[0].is_correct());
+        assert!(!record.keystrokes()[1].is_correct());
+        assert!(!record.keystrokes()[2].is_correct());
+    }
+}
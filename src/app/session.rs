@@ -0,0 +1,239 @@
+use app::word::Bucket;
+use app::exercise::{self, ErrorPolicy, Exercise, Stroke};
+
+
+
+pub fn new(bucket: &Bucket, error_policy: ErrorPolicy, word_policy: WordPolicy) -> Session {
+    let mut session = Session {
+        exercises: bucket.words.iter()
+            .map(|w| exercise::with_policy(w, error_policy))
+            .collect(),
+        current: 0,
+        policy: word_policy
+    };
+    session.settle();
+    session
+}
+
+
+//---
+/// How the session reacts at the end of a failed word.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WordPolicy {
+    /// Restart the failed word from the beginning.
+    Restart,
+    /// Move on, passed and failed words can no longer be changed.
+    Lock,
+    /// Move on, backspace can go back into the previous words.
+    Undo
+}
+
+
+//---
+/// Where the session went after a keystroke.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transition {
+    Stay,
+    // the failed word starts over
+    Restarted,
+    // the word is complete, and the next one is now current
+    Advanced
+}
+
+
+//---
+#[derive(Debug)]
+pub struct Session {
+    exercises: Vec<Exercise>,
+    current: usize,
+    policy: WordPolicy
+}
+
+impl Session {
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.exercises.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.exercises.is_empty()
+    }
+
+    /// Index of the word being typed.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn exercise(&self, i: usize) -> &Exercise {
+        &self.exercises[i]
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.exercises.len()
+    }
+
+    pub fn type_char(&mut self, typed: char) -> (Stroke, Transition) {
+        if self.is_finished() {
+            return (Stroke::Ignored, Transition::Stay);
+        }
+
+        let stroke = self.exercises[self.current].type_char(typed);
+        (stroke, self.settle())
+    }
+
+    /// Step back on the last typed character, crossing to the previous word
+    /// when the word policy allows it.
+    pub fn backspace(&mut self) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+
+        if self.exercises[self.current].backspace() {
+            return true;
+        }
+
+        let exercise = &mut self.exercises[self.current];
+        if self.policy != WordPolicy::Undo || exercise.progress() > 0 {
+            return false;
+        }
+        // a partly typed first grapheme is cleared before going back
+        if exercise.is_partial() {
+            return exercise.reopen();
+        }
+        if self.current == 0 {
+            return false;
+        }
+
+        // the previous word is done, it reopens on its last character
+        if self.exercises[self.current - 1].reopen() {
+            self.current -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    // skip over completed (or empty) words, restarting failed ones if needed
+    fn settle(&mut self) -> Transition {
+        let mut transition = Transition::Stay;
+        while !self.is_finished() && self.exercises[self.current].is_done() {
+            let exercise = &mut self.exercises[self.current];
+            if self.policy == WordPolicy::Restart && exercise.is_failed() {
+                exercise.reset();
+                return Transition::Restarted;
+            }
+            self.current += 1;
+            transition = Transition::Advanced;
+        }
+        transition
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn typed(session: &mut super::Session, text: &str) {
+        for c in text.chars() {
+            session.type_char(c);
+        }
+    }
+
+    #[test]
+    fn restart_failed_word() {
+        use super::*;
+        let bucket = Bucket::new(vec!["ab", "cd"]);
+        let mut session = new(&bucket, ErrorPolicy::Forward, WordPolicy::Restart);
+
+        typed(&mut session, "x");
+        assert_eq!(session.type_char('b'), (Stroke::Accepted, Transition::Restarted));
+        assert_eq!(session.current(), 0);
+        assert_eq!(session.exercise(0).progress(), 0);
+
+        typed(&mut session, "a");
+        assert_eq!(session.type_char('b'), (Stroke::Accepted, Transition::Advanced));
+        assert_eq!(session.current(), 1);
+    }
+
+    #[test]
+    fn lock_passed_words() {
+        use super::*;
+        let bucket = Bucket::new(vec!["ab", "cd"]);
+        let mut session = new(&bucket, ErrorPolicy::Correctable, WordPolicy::Lock);
+
+        typed(&mut session, "xb");
+        assert_eq!(session.current(), 1);
+        assert!(!session.backspace());
+        assert_eq!(session.current(), 1);
+
+        typed(&mut session, "cd");
+        assert!(session.is_finished());
+    }
+
+    #[test]
+    fn undo_across_words() {
+        use super::*;
+        let bucket = Bucket::new(vec!["ab", "cd"]);
+        let mut session = new(&bucket, ErrorPolicy::Correctable, WordPolicy::Undo);
+
+        typed(&mut session, "axc");
+        assert_eq!(session.current(), 1);
+
+        // back into the first word
+        assert!(session.backspace());
+        assert!(session.backspace());
+        assert_eq!(session.current(), 0);
+        assert_eq!(session.exercise(0).progress(), 1);
+
+        typed(&mut session, "b");
+        assert_eq!(session.current(), 1);
+        assert_eq!(session.exercise(1).progress(), 0);
+
+        typed(&mut session, "cd");
+        assert!(session.is_finished());
+        assert!(!session.backspace());
+    }
+
+    #[test]
+    fn undo_under_any_char_policy() {
+        use super::*;
+        let bucket = Bucket::new(vec!["ab", "cd"]);
+        for &policy in [ErrorPolicy::Block, ErrorPolicy::Forward].iter() {
+            let mut session = new(&bucket, policy, WordPolicy::Undo);
+
+            typed(&mut session, "ab");
+            assert_eq!(session.current(), 1);
+            assert!(session.backspace());
+            assert_eq!(session.current(), 0);
+            assert_eq!(session.exercise(0).progress(), 1);
+            assert_eq!(session.exercise(0).state(1), exercise::Typing::Pending);
+            // but no further back than the reopened character
+            assert!(!session.backspace());
+
+            typed(&mut session, "b");
+            assert_eq!(session.current(), 1);
+        }
+    }
+
+    #[test]
+    fn undo_clears_partial_grapheme_first() {
+        use super::*;
+        let bucket = Bucket::new(vec!["ab", "g\u{303}d"]);
+        for &policy in [ErrorPolicy::Block, ErrorPolicy::Forward].iter() {
+            let mut session = new(&bucket, policy, WordPolicy::Undo);
+
+            typed(&mut session, "abg");
+            assert!(session.exercise(1).is_partial());
+            assert!(session.backspace());
+            assert_eq!(session.current(), 1);
+            assert!(!session.exercise(1).is_partial());
+            assert_eq!(session.exercise(1).expected(), Some('g'));
+
+            // then back into the previous word
+            assert!(session.backspace());
+            assert_eq!(session.current(), 0);
+            typed(&mut session, "bg\u{303}d");
+            assert!(session.is_finished());
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use app::record::Record;
+
+
+// a word is 5 keystrokes, by convention of typing speed measures
+static CHARS_PER_WORD: f64 = 5.0;
+
+// entries kept in the rankings of the summary
+static RANKING_SIZE: usize = 5;
+
+
+//---
+/// Figures of a session, computed from its keystrokes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub duration: Duration,
+    pub keystrokes: usize,
+    pub errors: usize,
+    // words per minute, counting every keystroke
+    pub gross_wpm: f64,
+    // words per minute, errors deducted
+    pub net_wpm: f64,
+    // ratio of correct keystrokes, from 0 to 1
+    pub accuracy: f64,
+    // expected chars typed wrong, and how many times, the most missed first
+    pub missed: Vec<(char, usize)>,
+    // pairs of chars and the mean time to type the second, the slowest first
+    pub slowest_bigrams: Vec<(String, Duration)>
+}
+
+pub fn summarize(record: &Record) -> Summary {
+    let keystrokes = &record.chars()[..];
+    let duration = record.duration();
+    let errors = keystrokes.iter().filter(|k| !k.is_correct()).count();
+
+    let gross_wpm = gross_wpm(keystrokes.len(), duration);
+    let net_wpm = net_wpm(keystrokes.len(), errors, duration);
+    let accuracy = accuracy(keystrokes.len(), errors);
+
+    let mut missed: HashMap<char, usize> = HashMap::new();
+    for k in keystrokes.iter().filter(|k| !k.is_correct()) {
+        *missed.entry(k.expected).or_insert(0) += 1;
+    }
+    let mut missed: Vec<(char, usize)> = missed.into_iter().collect();
+    missed.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    missed.truncate(RANKING_SIZE);
+
+    // only two chars typed right in a row, within a word, make a bigram
+    let mut bigrams: HashMap<String, (Duration, u32)> = HashMap::new();
+    for pair in keystrokes.windows(2) {
+        let (first, second) = (&pair[0], &pair[1]);
+        if first.is_correct() && second.is_correct()
+            && first.word == second.word && first.offset + 1 == second.offset {
+            let bigram: String = [first.expected, second.expected].iter().collect();
+            let entry = bigrams.entry(bigram).or_insert((Duration::from_secs(0), 0));
+            entry.0 += second.at - first.at;
+            entry.1 += 1;
+        }
+    }
+    let mut slowest_bigrams: Vec<(String, Duration)> = bigrams.into_iter()
+        .map(|(bigram, (total, count))| (bigram, total / count))
+        .collect();
+    slowest_bigrams.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    slowest_bigrams.truncate(RANKING_SIZE);
+
+    Summary {
+        duration,
+        keystrokes: keystrokes.len(),
+        errors,
+        gross_wpm,
+        net_wpm,
+        accuracy,
+        missed,
+        slowest_bigrams
+    }
+}
+
+/// Words per minute, counting every keystroke.
+pub fn gross_wpm(keystrokes: usize, duration: Duration) -> f64 {
+    match minutes(duration) {
+        minutes if minutes > 0.0 => keystrokes as f64 / CHARS_PER_WORD / minutes,
+        _ => 0.0
+    }
+}
+
+/// Words per minute, with a word deducted for each error.
+pub fn net_wpm(keystrokes: usize, errors: usize, duration: Duration) -> f64 {
+    match minutes(duration) {
+        minutes if minutes > 0.0 => (gross_wpm(keystrokes, duration) - errors as f64 / minutes).max(0.0),
+        _ => 0.0
+    }
+}
+
+/// Ratio of correct keystrokes, from 0 to 1.
+pub fn accuracy(keystrokes: usize, errors: usize) -> f64 {
+    match keystrokes {
+        0 => 0.0,
+        _ => (keystrokes - errors) as f64 / keystrokes as f64
+    }
+}
+
+fn minutes(duration: Duration) -> f64 {
+    (duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) / 1e9) / 60.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::record::{Keystroke, Kind};
+
+    fn record(keys: &[(u64, char, char, usize, usize)]) -> Record {
+        let mut record = Record::default();
+        for &(ms, expected, typed, word, offset) in keys {
+            record.push(Keystroke { at: Duration::from_millis(ms), kind: Kind::Char, expected, typed, word, offset });
+        }
+        record
+    }
+
+    #[test]
+    fn empty_session() {
+        let summary = summarize(&Record::default());
+
+        assert_eq!(summary.keystrokes, 0);
+        assert_eq!(summary.gross_wpm, 0.0);
+        assert_eq!(summary.accuracy, 0.0);
+        assert!(summary.missed.is_empty());
+    }
+
+    #[test]
+    fn speed_and_accuracy() {
+        // 10 keystrokes in 6 seconds, 2 of them wrong
+        let keys: Vec<(u64, char, char, usize, usize)> = (0..10)
+            .map(|i| (i * 6000 / 9, 'a', if i % 5 == 0 { 'x' } else { 'a' }, 0, i as usize))
+            .collect();
+        let summary = summarize(&record(&keys));
+
+        assert_eq!(summary.duration, Duration::from_secs(6));
+        assert_eq!(summary.errors, 2);
+        assert!((summary.gross_wpm - 20.0).abs() < 1e-9);
+        assert!((summary.net_wpm - 0.0).abs() < 1e-9);
+        assert!((summary.accuracy - 0.8).abs() < 1e-9);
+        assert_eq!(summary.missed, vec![('a', 2)]);
+
+        // keystrokes evenly spread over the duration, the first ones wrong
+        let session = |count: u64, errors: u64, ms: u64| {
+            let keys: Vec<(u64, char, char, usize, usize)> = (0..count)
+                .map(|i| (i * ms / (count - 1), 'a', if i < errors { 'x' } else { 'a' }, 0, i as usize))
+                .collect();
+            summarize(&record(&keys))
+        };
+        let figures = |summary: Summary| (summary.gross_wpm, summary.net_wpm, summary.accuracy);
+
+        // 50 keystrokes in a minute are 10 words
+        assert_eq!(figures(session(50, 0, 60_000)), (10.0, 10.0, 1.0));
+        // 100 keystrokes in 30 seconds are 40 words, 5 errors take off 10
+        assert_eq!(figures(session(100, 5, 30_000)), (40.0, 30.0, 0.95));
+        // 30 keystrokes in 2 minutes are 3 words, 9 errors take off more
+        assert_eq!(figures(session(30, 9, 120_000)), (3.0, 0.0, 0.7));
+    }
+
+    #[test]
+    fn rank_missed_chars_and_slow_bigrams() {
+        let summary = summarize(&record(&[
+            (0, 't', 't', 0, 0),
+            (300, 'h', 'h', 0, 1),
+            (400, 'e', 'x', 0, 2),
+            (500, 'e', 'e', 0, 2),
+            // next word, not a bigram with the previous char
+            (2000, 'a', 'a', 1, 0),
+            (2100, 't', 't', 1, 1),
+            (2200, 'h', 'y', 1, 2),
+            (2300, 'h', 'j', 1, 2),
+            (2400, 'h', 'h', 1, 2)
+        ]));
+
+        assert_eq!(summary.missed, vec![('h', 2), ('e', 1)]);
+        assert_eq!(summary.slowest_bigrams, vec![
+            ("th".to_string(), Duration::from_millis(300)),
+            ("at".to_string(), Duration::from_millis(100))
+        ]);
+    }
+}
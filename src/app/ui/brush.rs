@@ -13,89 +13,106 @@ pub static ACS_VLINE: char = '│';
 
 
 //---
-pub fn write_at(keycode: char, pos: &Pos, output: &mut io::Write) -> io::Result<()> {
-    try!(writeln!(output, "{}{}", pos, keycode));
+pub fn write_at(keycode: char, pos: &Pos, output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(output, "{}{}", pos, keycode)?;
     Ok(())
 }
 
 
-pub fn write_hline(keycode: char, pos: &Pos, w: u16, output: &mut io::Write) -> io::Result<()> {
+pub fn write_hline(keycode: char, pos: &Pos, w: u16, output: &mut dyn io::Write) -> io::Result<()> {
     let line = keycode.to_string().repeat(w as usize);
-    try!(writeln!(output, "{}{}", pos, line));
+    writeln!(output, "{}{}", pos, line)?;
     Ok(())
 }
 
 
-pub fn write_vline(keycode: char, pos: &Pos, h: u16, output: &mut io::Write) -> io::Result<()> {
+pub fn write_vline(keycode: char, pos: &Pos, h: u16, output: &mut dyn io::Write) -> io::Result<()> {
     for dy in 0..h {
         let rpos = Pos { x: pos.x, y: pos.y + dy };
-        try!(writeln!(output, "{}{}", rpos, keycode));
+        writeln!(output, "{}{}", rpos, keycode)?;
     }
     Ok(())
 }
 
 
-pub fn write_frame(pos: Pos, dim: Dim, output: &mut io::Write) -> io::Result<()> {
+pub fn write_frame(pos: Pos, dim: Dim, output: &mut dyn io::Write) -> io::Result<()> {
     if dim.h == 0 || dim.w == 0 {
         return Ok(());
     }
 
     // top left
-    try!(write_at(ACS_TLCORNER, &Pos {
+    write_at(ACS_TLCORNER, &Pos {
         x: pos.x,
         y: pos.y
-    }, output));
+    }, output)?;
 
     // top right
-    try!(write_at(ACS_TRCORNER, &Pos {
+    write_at(ACS_TRCORNER, &Pos {
         x: pos.x + dim.w - 1,
         y: pos.y
-    }, output));
+    }, output)?;
 
     // bottom left
-    try!(write_at(ACS_BLCORNER, &Pos {
+    write_at(ACS_BLCORNER, &Pos {
         x: pos.x,
         y: pos.y + dim.h - 1
-    }, output));
+    }, output)?;
 
     // bottom right
-    try!(write_at(ACS_BRCORNER, &Pos {
+    write_at(ACS_BRCORNER, &Pos {
         x: pos.x + dim.w - 1,
         y: pos.y + dim.h - 1
-    }, output));
+    }, output)?;
 
     // top line
     if dim.w > 1 {
-        try!(write_hline(ACS_HLINE, &Pos {
+        write_hline(ACS_HLINE, &Pos {
             x: pos.x + 1,
             y: pos.y
-        }, dim.w - 2, output));
+        }, dim.w - 2, output)?;
 
         // bottom line
-        try!(write_hline(ACS_HLINE, &Pos {
+        write_hline(ACS_HLINE, &Pos {
             x: pos.x + 1,
             y: pos.y + dim.h - 1
-        }, dim.w - 2 , output));
+        }, dim.w - 2 , output)?;
     }
 
     if dim.h > 1 {
         // right line
-        try!(write_vline(ACS_VLINE, &Pos {
+        write_vline(ACS_VLINE, &Pos {
             x: pos.x + dim.w - 1,
             y: pos.y + 1
-        }, dim.h - 2, output));
+        }, dim.h - 2, output)?;
 
         // left line
-        try!(write_vline(ACS_VLINE, &Pos {
+        write_vline(ACS_VLINE, &Pos {
             x: pos.x,
             y: pos.y + 1
-        }, dim.h - 2, output));
+        }, dim.h - 2, output)?;
     }
 
     Ok(())
 }
 
 
-pub fn write_frame2(bbox: BoundingBox, gap: u16, output: &mut io::Write) -> io::Result<()> {
-    write_frame(bbox.pos().shift(-1 * gap as i16, -1 * gap as i16), bbox.dim().grow(gap * 2, gap * 2), output)
+pub fn write_frame2(bbox: &BoundingBox, gap: u16, output: &mut dyn io::Write) -> io::Result<()> {
+    write_frame(bbox.pos().shift(-(gap as i16), -(gap as i16)), bbox.dim().grow(gap * 2, gap * 2), output)
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn frame_around_box() {
+        use super::*;
+        use app::ui::screen::Screen;
+
+        let mut screen = Screen::new(6, 5);
+        let bbox = BoundingBox { x: 3, y: 3, w: 2, h: 1 };
+        write_frame2(&bbox, 1, &mut screen).unwrap();
+
+        assert_eq!(screen.row(2), " ┌──┐");
+        assert_eq!(screen.row(3), " │  │");
+        assert_eq!(screen.row(4), " └──┘");
+    }
 }
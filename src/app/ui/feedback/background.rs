@@ -0,0 +1,27 @@
+use std::io;
+
+use termion::style;
+
+use app::exercise::Exercise;
+use app::ui::Pos;
+use app::ui::feedback::{self, Feedback};
+use app::ui::render::Cursor;
+use app::ui::theme::Theme;
+
+
+//---
+/// Each char on the color of its state, the mistyped ones in place of the
+/// expected ones.
+#[derive(Debug)]
+pub struct Background;
+
+impl Feedback for Background {
+    fn write_word(&self, pos: &Pos, exercise: &Exercise, cursor: Cursor, theme: &Theme,
+                  output: &mut dyn io::Write) -> io::Result<()> {
+        for i in 0..exercise.len() {
+            let (role, text) = feedback::background(exercise, i, cursor);
+            feedback::write_at(&feedback::at(pos, exercise, i), role, &text, theme, output)?;
+        }
+        write!(output, "{}", style::Reset)
+    }
+}
@@ -0,0 +1,53 @@
+use std::io;
+
+use termion::style;
+
+use app::exercise::Exercise;
+use app::ui::Pos;
+use app::ui::feedback::{self, Feedback};
+use app::ui::render::Cursor;
+use app::ui::theme::{Role, Theme};
+
+
+//---
+/// The chars left to type and the cursor, the typed ones being hidden.
+#[derive(Debug)]
+pub struct Blind;
+
+impl Feedback for Blind {
+    fn write_word(&self, pos: &Pos, exercise: &Exercise, cursor: Cursor, theme: &Theme,
+                  output: &mut dyn io::Write) -> io::Result<()> {
+        for i in 0..exercise.len() {
+            let (role, text) = match feedback::background(exercise, i, cursor) {
+                (Role::Correct, _) | (Role::Incorrect, _) | (Role::Corrected, _) => (Role::Pending, feedback::blank(exercise, i)),
+                shown => shown
+            };
+            feedback::write_at(&feedback::at(pos, exercise, i), role, &text, theme, output)?;
+        }
+        write!(output, "{}", style::Reset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use super::*;
+    use app::exercise::{self, ErrorPolicy};
+    use app::ui::screen::{Color, Screen};
+    use app::word::Word;
+
+    #[test]
+    fn typed_chars_hidden() {
+        let mut exercise = exercise::with_policy(&Word::new("漢字ab"), ErrorPolicy::Forward);
+        exercise.type_char('漢');
+        exercise.type_char('x');
+
+        let mut screen = Screen::new(7, 1);
+        write!(screen, "{}", "#".repeat(7)).unwrap();
+        Blind.write_word(&Pos { x: 1, y: 1 }, &exercise, Cursor::Waiting, &Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.row(1), "    ab#");
+        assert_eq!(screen.cell(1, 1).bg, Color::Reset);
+        assert_eq!(screen.cell(5, 1).bg, Color::Ansi(5));
+    }
+}
@@ -0,0 +1,63 @@
+use std::io;
+
+use termion::style;
+
+use app::exercise::Exercise;
+use app::ui::Pos;
+use app::ui::feedback::{self, Feedback};
+use app::ui::feedback::background::Background;
+use app::ui::render::Cursor;
+use app::ui::theme::{Role, Theme};
+
+
+//---
+/// Only the cursor while the word is typed, the state of each char being
+/// shown once the word is done.
+#[derive(Debug)]
+pub struct Caret;
+
+impl Feedback for Caret {
+    fn write_word(&self, pos: &Pos, exercise: &Exercise, cursor: Cursor, theme: &Theme,
+                  output: &mut dyn io::Write) -> io::Result<()> {
+        if exercise.is_done() {
+            return Background.write_word(pos, exercise, cursor, theme, output);
+        }
+
+        for i in 0..exercise.len() {
+            // typed chars are shown as expected, right or wrong
+            let (role, text) = match feedback::background(exercise, i, cursor) {
+                (Role::Correct, _) | (Role::Incorrect, _) | (Role::Corrected, _) =>
+                    (Role::Pending, exercise[i].to_string()),
+                shown => shown
+            };
+            feedback::write_at(&feedback::at(pos, exercise, i), role, &text, theme, output)?;
+        }
+        write!(output, "{}", style::Reset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::exercise::{self, ErrorPolicy};
+    use app::ui::screen::{Color, Screen};
+    use app::word::Word;
+
+    #[test]
+    fn correctness_at_the_end() {
+        let mut exercise = exercise::with_policy(&Word::new("ab"), ErrorPolicy::Forward);
+        let mut screen = Screen::new(3, 1);
+        let pos = Pos { x: 1, y: 1 };
+
+        exercise.type_char('x');
+        Caret.write_word(&pos, &exercise, Cursor::Waiting, &Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.row(1), "ab");
+        assert_eq!(screen.cell(1, 1).bg, Color::Reset);
+        assert_eq!(screen.cell(2, 1).bg, Color::Ansi(5));
+
+        exercise.type_char('b');
+        Caret.write_word(&pos, &exercise, Cursor::Absent, &Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.cell(1, 1).bg, Color::Ansi(1));
+        assert_eq!(screen.cell(2, 1).bg, Color::Ansi(2));
+    }
+}
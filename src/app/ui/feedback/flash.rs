@@ -0,0 +1,67 @@
+use std::io;
+use std::time::Duration;
+
+use app::engine::Change;
+use app::exercise::Exercise;
+use app::ui::Pos;
+use app::ui::feedback::Feedback;
+use app::ui::feedback::background::Background;
+use app::ui::render::Cursor;
+use app::ui::theme::{Role, Theme};
+
+
+// time the frame stays flashed after a mistake
+static FLASH: Duration = Duration::from_millis(200);
+
+
+//---
+/// As the background mode, the frame around the words flashing on each
+/// mistake.
+#[derive(Debug)]
+pub struct Flash {
+    // session time at which the frame is back to normal
+    until: Option<Duration>
+}
+
+pub fn new() -> Flash {
+    Flash { until: None }
+}
+
+impl Feedback for Flash {
+    fn write_word(&self, pos: &Pos, exercise: &Exercise, cursor: Cursor, theme: &Theme,
+                  output: &mut dyn io::Write) -> io::Result<()> {
+        Background.write_word(pos, exercise, cursor, theme, output)
+    }
+
+    fn notice(&mut self, changes: &[Change], at: Duration) {
+        if changes.iter().any(|change| matches!(*change, Change::Rejected { .. })) {
+            self.until = Some(at + FLASH);
+        }
+    }
+
+    fn frame_role(&self, at: Duration) -> Role {
+        match self.until {
+            Some(until) if at < until => Role::Incorrect,
+            _ => Role::Frame
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn flash_on_mistake() {
+        let mut flash = new();
+        let at = Duration::from_secs(2);
+        assert_eq!(flash.frame_role(at), Role::Frame);
+
+        flash.notice(&[Change::Accepted { word: 0, offset: 0 }], at);
+        assert_eq!(flash.frame_role(at), Role::Frame);
+
+        flash.notice(&[Change::Rejected { word: 0, offset: 1, typed: 'x' }], at);
+        assert_eq!(flash.frame_role(at + Duration::from_millis(100)), Role::Incorrect);
+        assert_eq!(flash.frame_role(at + FLASH), Role::Frame);
+    }
+}
@@ -0,0 +1,111 @@
+use std::io;
+use std::time::Duration;
+
+use app::engine::Change;
+use app::exercise::{Exercise, Typing};
+use app::ui::Pos;
+use app::ui::render::Cursor;
+use app::ui::theme::{Role, Theme};
+use app::word;
+
+pub mod background;
+pub mod blind;
+pub mod caret;
+pub mod flash;
+pub mod overtype;
+
+
+static MODES: [&str; 5] = ["background", "overtype", "caret", "blind", "flash"];
+
+
+//---
+/// How the words, and what was typed of them, are shown.
+pub trait Feedback {
+    /// Lines taken by each row of words, the words being on the last one.
+    fn line_height(&self) -> u16 {
+        1
+    }
+
+    /// Paint a word in its current state.
+    fn write_word(&self, pos: &Pos, exercise: &Exercise, cursor: Cursor, theme: &Theme,
+                  output: &mut dyn io::Write) -> io::Result<()>;
+
+    /// Take the changes of a keystroke into account, typed `at` that time
+    /// of the session.
+    fn notice(&mut self, _changes: &[Change], _at: Duration) {}
+
+    /// Role of the frame around the words, at that time of the session.
+    fn frame_role(&self, _at: Duration) -> Role {
+        Role::Frame
+    }
+}
+
+/// Renderer of a mode, by its name.
+pub fn new(mode: &str) -> Option<Box<dyn Feedback>> {
+    match mode {
+        "background" => Some(Box::new(background::Background)),
+        "overtype" => Some(Box::new(overtype::Overtype)),
+        "caret" => Some(Box::new(caret::Caret)),
+        "blind" => Some(Box::new(blind::Blind)),
+        "flash" => Some(Box::new(flash::new())),
+        _ => None
+    }
+}
+
+pub fn names() -> &'static [&'static str] {
+    &MODES
+}
+
+
+//---
+/// Role and text of a grapheme in the background mode: the mistyped char
+/// in place of the expected one, padded to its width, or the expected one
+/// if the typed one doesn't fit.
+fn background(exercise: &Exercise, i: usize, cursor: Cursor) -> (Role, String) {
+    let progress = i == exercise.progress();
+    match (exercise.state(i), cursor) {
+        (_, Cursor::Waiting) if progress => (Role::Current, exercise[i].to_string()),
+        (_, Cursor::Composing) if progress => (Role::Composing, exercise[i].to_string()),
+        (Typing::Pending, _) => (Role::Pending, exercise[i].to_string()),
+        (Typing::Correct, _) => (Role::Correct, exercise[i].to_string()),
+        (Typing::Corrected, _) => (Role::Corrected, exercise[i].to_string()),
+        (Typing::Incorrect(typed), _) => (Role::Incorrect, mistyped(exercise, i, typed))
+    }
+}
+
+fn mistyped(exercise: &Exercise, i: usize, typed: char) -> String {
+    let width = exercise.width(i);
+    match word::width(&typed.to_string()) {
+        typed_width if typed_width <= width => format!("{}{}", typed, " ".repeat(width - typed_width)),
+        _ => exercise[i].to_string()
+    }
+}
+
+/// Blanks as wide as the grapheme.
+fn blank(exercise: &Exercise, i: usize) -> String {
+    " ".repeat(exercise.width(i))
+}
+
+/// Position of the grapheme, the word being at `pos`.
+fn at(pos: &Pos, exercise: &Exercise, i: usize) -> Pos {
+    pos.shift(exercise.column(i) as i16, 0)
+}
+
+fn write_at(pos: &Pos, role: Role, text: &str, theme: &Theme, output: &mut dyn io::Write) -> io::Result<()> {
+    write!(output, "{}{}{}", theme.paint(role), pos, text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn modes_by_name() {
+        for name in names() {
+            assert!(new(name).is_some(), "{}", name);
+        }
+        assert!(new("rainbow").is_none());
+        assert_eq!(new("overtype").unwrap().line_height(), 2);
+        assert_eq!(new("caret").unwrap().line_height(), 1);
+    }
+}
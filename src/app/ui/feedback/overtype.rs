@@ -0,0 +1,66 @@
+use std::io;
+
+use termion::style;
+
+use app::exercise::{Exercise, Typing};
+use app::ui::Pos;
+use app::ui::feedback::{self, Feedback};
+use app::ui::render::Cursor;
+use app::ui::theme::{Role, Theme};
+
+
+//---
+/// Each char on the color of its state, the expected ones staying in place
+/// and the mistyped ones shown on the line above them.
+#[derive(Debug)]
+pub struct Overtype;
+
+impl Feedback for Overtype {
+    fn line_height(&self) -> u16 {
+        2
+    }
+
+    fn write_word(&self, pos: &Pos, exercise: &Exercise, cursor: Cursor, theme: &Theme,
+                  output: &mut dyn io::Write) -> io::Result<()> {
+        for i in 0..exercise.len() {
+            let (role, _) = feedback::background(exercise, i, cursor);
+            feedback::write_at(&feedback::at(pos, exercise, i), role, &exercise[i], theme, output)?;
+
+            // kept while the cursor is blocked on the char
+            let (role, above) = match exercise.state(i) {
+                Typing::Incorrect(typed) => (Role::Incorrect, feedback::mistyped(exercise, i, typed)),
+                _ => (Role::Pending, feedback::blank(exercise, i))
+            };
+            feedback::write_at(&feedback::at(pos, exercise, i).shift(0, -1), role, &above, theme, output)?;
+        }
+        write!(output, "{}", style::Reset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::exercise::{self, ErrorPolicy};
+    use app::ui::screen::{Color, Screen};
+    use app::word::Word;
+
+    #[test]
+    fn mistyped_above_expected() {
+        let mut exercise = exercise::with_policy(&Word::new("abc"), ErrorPolicy::Block);
+        exercise.type_char('a');
+        exercise.type_char('x');
+
+        let mut screen = Screen::new(5, 2);
+        Overtype.write_word(&Pos { x: 2, y: 2 }, &exercise, Cursor::Waiting, &Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.row(1), "  x");
+        assert_eq!(screen.row(2), " abc");
+        assert_eq!(screen.cell(3, 1).bg, Color::Ansi(1));
+        assert_eq!(screen.cell(3, 2).bg, Color::Ansi(5));
+
+        // gone once typed right
+        exercise.type_char('b');
+        Overtype.write_word(&Pos { x: 2, y: 2 }, &exercise, Cursor::Waiting, &Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.row(1), "");
+        assert_eq!(screen.row(2), " abc");
+    }
+}
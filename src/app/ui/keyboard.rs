@@ -0,0 +1,155 @@
+use std::io;
+
+use termion::style;
+
+use app::geometry::{Geometry, Placement};
+use app::layout::{Layout, Level};
+use app::ui::brush;
+use app::ui::{Pos, Dim};
+use app::ui::theme::{Role, Theme};
+use app::word;
+
+
+//---
+/// Diagram of the keyboard, a language layout drawn on a physical
+/// geometry. Each quarter of a key takes a column, each row a line.
+#[derive(Debug)]
+pub struct Keyboard {
+    pub layout: Layout,
+    pub geometry: Geometry
+}
+
+pub fn new(layout: Layout, geometry: Geometry) -> Keyboard {
+    Keyboard { layout, geometry }
+}
+
+impl Keyboard {
+    /// Room taken by the diagram, frame included.
+    pub fn dim(&self) -> Dim {
+        Dim {
+            w: self.geometry.width() + 1,
+            h: self.geometry.rows.len() as u16 + 2
+        }
+    }
+
+    /// Keys to press for a char: the key itself, then its modifiers. A
+    /// char without key of its own starts with its dead key.
+    pub fn keys_for(&self, c: char) -> Vec<String> {
+        let placement = match self.geometry.locate(&self.layout, c) {
+            Some(Placement { dead: Some(dead), .. }) => *dead,
+            Some(placement) => placement,
+            None => return Vec::new()
+        };
+        let mut keys = vec![placement.code];
+        keys.extend(placement.modifiers);
+        keys
+    }
+
+    /// Draw the diagram, highlighting the keys to type the next char.
+    pub fn write(&self, pos: &Pos, next: Option<char>, theme: &Theme, output: &mut dyn io::Write) -> io::Result<()> {
+        let highlighted = next.map(|c| self.keys_for(c)).unwrap_or_default();
+        let frame = theme.paint(Role::Frame);
+
+        write!(output, "{}", frame)?;
+        brush::write_frame(pos.clone(), self.dim(), output)?;
+
+        for (i, row) in self.geometry.rows.iter().enumerate() {
+            let y = pos.y + 1 + i as u16;
+            for slot in row.iter() {
+                let inner = (slot.width - 1) as usize;
+                let label = word::fit(&self.label(&slot.code), inner);
+                let shown = word::width(&label);
+                let left = (inner - shown) / 2;
+                let right = inner - shown - left;
+
+                let key = match highlighted.contains(&slot.code) {
+                    true => theme.paint(Role::Current),
+                    false => theme.paint(Role::Pending)
+                };
+                write!(output, "{}{}{}{}{}{}{}{}",
+                       Pos { x: pos.x + slot.x, y }, frame, brush::ACS_VLINE,
+                       key, " ".repeat(left), label, " ".repeat(right),
+                       style::Reset)?;
+            }
+            if let Some(last) = row.last() {
+                write!(output, "{}", frame)?;
+                brush::write_at(brush::ACS_VLINE, &Pos { x: pos.x + last.x + last.width, y }, output)?;
+            }
+        }
+        write!(output, "{}", style::Reset)
+    }
+
+    fn label(&self, code: &str) -> String {
+        match code {
+            "TAB" => "tab".to_string(),
+            "CAPS" => "caps".to_string(),
+            "LFSH" | "RTSH" => "shift".to_string(),
+            "BKSP" => "bksp".to_string(),
+            "DELE" => "del".to_string(),
+            "RTRN" => "enter".to_string(),
+            "LCTL" | "RCTL" => "ctrl".to_string(),
+            "LWIN" | "RWIN" => "win".to_string(),
+            "LALT" => "alt".to_string(),
+            "RALT" => "altgr".to_string(),
+            "MENU" => "menu".to_string(),
+            code => self.layout.key(code)
+                .and_then(|key| key.symbol(Level::Base))
+                .map(|symbol| symbol.to_string())
+                .unwrap_or_default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::ui::screen::{Color, Screen};
+
+    const MAGENTA: Color = Color::Ansi(5);
+
+    fn qwerty() -> Keyboard {
+        new(Layout::builtin("qwerty-us").unwrap(), Geometry::builtin("ansi-104").unwrap())
+    }
+
+    #[test]
+    fn draw_rows_of_keys() {
+        let keyboard = qwerty();
+        assert_eq!(keyboard.dim(), Dim { w: 61, h: 7 });
+
+        let mut screen = Screen::new(61, 7);
+        keyboard.write(&Pos { x: 1, y: 1 }, None, &Theme::default(), &mut screen).unwrap();
+
+        assert!(screen.row(1).starts_with("┌───"));
+        assert!(screen.row(4).starts_with("│ caps │ a │ s │ d │"), "{}", screen.row(4));
+        assert!(screen.row(5).starts_with("│ shift  │ z │"), "{}", screen.row(5));
+        assert!(screen.row(7).starts_with("└───"));
+    }
+
+    #[test]
+    fn highlight_key_and_modifier() {
+        let keyboard = qwerty();
+        let mut screen = Screen::new(61, 7);
+        keyboard.write(&Pos { x: 1, y: 1 }, Some('A'), &Theme::default(), &mut screen).unwrap();
+
+        // the `a` key, then the shift of the other hand
+        assert_eq!(screen.text(9, 4, 3), " a ");
+        assert_eq!(screen.cell(10, 4).bg, MAGENTA);
+        assert_eq!(screen.cell(14, 4).bg, Color::Reset);
+        assert_eq!(screen.cell(2, 5).bg, Color::Reset);
+        assert_eq!(screen.cell(55, 5).bg, MAGENTA);
+
+        keyboard.write(&Pos { x: 1, y: 1 }, Some('s'), &Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.cell(10, 4).bg, Color::Reset);
+        assert_eq!(screen.cell(55, 5).bg, Color::Reset);
+    }
+
+    #[test]
+    fn dead_key_first() {
+        let keyboard = new(Layout::builtin("bepo").unwrap(), Geometry::builtin("iso-105").unwrap());
+
+        assert_eq!(keyboard.keys_for('ê'), vec!["LSGT"]);
+        assert_eq!(keyboard.keys_for('ë'), vec!["AC03", "RALT"]);
+        assert_eq!(keyboard.keys_for('ǎ'), vec!["AD07", "RALT"]);
+        assert!(keyboard.keys_for('ǖ').is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::io;
 use std::cmp::{max, min};
 
 use termion;
@@ -6,26 +7,34 @@ use termion;
 use app::word::Bucket;
 
 pub mod brush;
+pub mod feedback;
+pub mod keyboard;
+pub mod render;
+pub mod status;
+pub mod terminal;
+pub mod theme;
+#[cfg(test)]
+pub mod screen;
 
 
 //---
-#[allow(dead_code)]
-#[derive(Debug)]
+#[allow(dead_code, clippy::enum_variant_names)]
+#[derive(Debug, Clone)]
 pub enum HAlignment {
     AlignLeft,
     AlignMiddle,
     AlignRight
 }
 
-#[allow(dead_code)]
-#[derive(Debug)]
+#[allow(dead_code, clippy::enum_variant_names)]
+#[derive(Debug, Clone)]
 pub enum VAlignment {
     AlignTop,
     AlignCenter,
     AlignBottom
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Alignment {
     vert: VAlignment,
     hori: HAlignment
@@ -55,12 +64,38 @@ impl Alignment {
             hori: HAlignment::AlignRight
         }
     }
+
+    pub fn names() -> &'static [&'static str] {
+        &ALIGNMENTS
+    }
+
+    /// Alignment by name, the vertical side then the horizontal one, a
+    /// centered side being left out: `top-left`, `top`, `center`...
+    pub fn parse(text: &str) -> Option<Alignment> {
+        let (vert, hori) = match text {
+            "top-left" => (VAlignment::AlignTop, HAlignment::AlignLeft),
+            "top" => (VAlignment::AlignTop, HAlignment::AlignMiddle),
+            "top-right" => (VAlignment::AlignTop, HAlignment::AlignRight),
+            "left" => (VAlignment::AlignCenter, HAlignment::AlignLeft),
+            "center" => (VAlignment::AlignCenter, HAlignment::AlignMiddle),
+            "right" => (VAlignment::AlignCenter, HAlignment::AlignRight),
+            "bottom-left" => (VAlignment::AlignBottom, HAlignment::AlignLeft),
+            "bottom" => (VAlignment::AlignBottom, HAlignment::AlignMiddle),
+            "bottom-right" => (VAlignment::AlignBottom, HAlignment::AlignRight),
+            _ => return None
+        };
+        Some(Alignment { vert, hori })
+    }
 }
 
+static ALIGNMENTS: [&str; 9] = [
+    "top-left", "top", "top-right", "left", "center", "right", "bottom-left", "bottom", "bottom-right"
+];
+
 
 //---
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Measurement<T> {
     Value(T),
     Infinite
@@ -80,12 +115,14 @@ pub struct AdaptativeDim {
 pub struct Constraint {
     pub origin: Pos,
     pub dim: AdaptativeDim,
-    pub align: Alignment
+    pub align: Alignment,
+    // lines taken by each row, the words being on the last one
+    pub line_height: u16
 }
 
 
 //---
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Pos {
     // TODO keep it only visible inside the crate
     pub x: u16,
@@ -102,9 +139,9 @@ impl Pos {
     }
 }
 
-impl<'a> Into<termion::cursor::Goto> for &'a Pos {
-    fn into(self) -> termion::cursor::Goto {
-        termion::cursor::Goto(self.x, self.y)
+impl From<&Pos> for termion::cursor::Goto {
+    fn from(pos: &Pos) -> termion::cursor::Goto {
+        termion::cursor::Goto(pos.x, pos.y)
     }
 }
 
@@ -127,7 +164,7 @@ pub struct Dim {
 
 impl Dim {
     pub fn shrink(&self, incrw: u16, incrh: u16) -> Dim {
-        Dim { w: self.w - incrw, h: self.h - incrh }
+        Dim { w: self.w.saturating_sub(incrw), h: self.h.saturating_sub(incrh) }
     }
 
     pub fn grow(&self, incrw: u16, incrh: u16) -> Dim {
@@ -135,21 +172,21 @@ impl Dim {
     }
 }
 
-impl Into<AdaptativeDim> for Dim {
-    fn into(self) -> AdaptativeDim {
+impl From<Dim> for AdaptativeDim {
+    fn from(dim: Dim) -> AdaptativeDim {
         AdaptativeDim {
-            width: Measurement::Value(self.w),
-            height: Measurement::Value(self.h)
+            width: Measurement::Value(dim.w),
+            height: Measurement::Value(dim.h)
         }
     }
 }
 
-pub fn term_dim() -> Dim {
-    let size = termion::terminal_size().expect("no size of terminal");
-    Dim {
-        h: size.1 - 2,
-        w: size.0 - 1
-    }
+pub fn term_dim() -> io::Result<Dim> {
+    let size = termion::terminal_size()?;
+    Ok(Dim {
+        h: size.1.saturating_sub(2),
+        w: size.0.saturating_sub(1)
+    })
 }
 
 
@@ -184,27 +221,29 @@ pub enum LayoutError {
     // index of the word which overflows
     TooWide(usize),
     // index of the word which overflows
-    TooManyWords(usize)
+    TooManyWords(usize),
+    // the keyboard diagram doesn't fit under the words
+    NoRoomForKeyboard
 }
 
 impl Constraint {
     pub fn organize(&self, bucket: &Bucket) -> Result<(Vec<Pos>, BoundingBox), LayoutError> {
-        let (mut poss, surface, rows) = try!(self.split_roughly(&bucket));
+        let (mut poss, surface, rows) = self.split_roughly(bucket)?;
         let bbox = self.align(&mut poss, &surface, &rows);
         Ok((poss, bbox))
     }
 
-    fn align(&self, rough_pos: &mut Vec<Pos>, area_size: &Dim, rows_length: &Vec<u16>) -> BoundingBox {
-        if rough_pos.len() == 0 {
+    fn align(&self, rough_pos: &mut [Pos], area_size: &Dim, rows_length: &[u16]) -> BoundingBox {
+        if rough_pos.is_empty() {
             return BoundingBox { x: self.origin.x, y: self.origin.y, w: 0, h: 0 };
         }
 
-        let mut bbox = BoundingBox { x: u16::max_value(), y: 0, w: 0, h: 0 };
+        let mut bbox = BoundingBox { x: u16::MAX, y: 0, w: 0, h: 0 };
 
         let offset_y = match self.dim.height {
             Measurement::Infinite => 0,
             Measurement::Value(height) => {
-                debug_assert!(area_size.h <= height, format!("{} <= {}", area_size.h, height));
+                debug_assert!(area_size.h <= height, "{} <= {}", area_size.h, height);
                 match self.align.vert {
                     VAlignment::AlignTop => 0,
                     VAlignment::AlignCenter => (height - area_size.h) / 2,
@@ -212,19 +251,20 @@ impl Constraint {
                 }
             }
         };
-        bbox.y = rough_pos.first().expect("not possible").y;
+        // the lines above the first row are part of it
+        bbox.y = rough_pos.first().expect("not possible").y + 1 - self.line_height;
         bbox.h = rough_pos.last().expect("not possible").y - bbox.y + 1;
-        bbox.y = bbox.y + offset_y;
+        bbox.y += offset_y;
 
-        for ref mut pos in rough_pos.into_iter() {
-            let row_length = rows_length[(pos.y - self.origin.y) as usize];
+        for pos in rough_pos.iter_mut() {
+            let row_length = rows_length[((pos.y - self.origin.y) / self.line_height) as usize];
 
             bbox.w = max(bbox.w, row_length);
 
             let offset_x = match self.dim.width {
                 Measurement::Infinite => 0,
                 Measurement::Value(width) => {
-                    debug_assert!(row_length <= width, format!("{} <= {}", row_length, width));
+                    debug_assert!(row_length <= width, "{} <= {}", row_length, width);
                     match self.align.hori {
                         HAlignment::AlignLeft => 0,
                         HAlignment::AlignMiddle => (width - row_length) / 2,
@@ -235,7 +275,7 @@ impl Constraint {
 
             bbox.x = min(bbox.x, pos.x + offset_x);
 
-            **pos = Pos {
+            *pos = Pos {
                 x: pos.x + offset_x,
                 y: pos.y + offset_y
             };
@@ -246,23 +286,30 @@ impl Constraint {
 
     fn split_roughly(&self, bucket: &Bucket) -> Result<(Vec<Pos>, Dim, Vec<u16>), LayoutError> {
         if bucket.words.is_empty() { return Ok((Vec::new(), Dim { w: 0, h: 0 }, Vec::new())); }
+        if let Measurement::Value(frame_height) = self.dim.height {
+            if frame_height < self.line_height {
+                return Err(LayoutError::TooManyWords(0));
+            }
+        }
 
         let sep: u16 = 1;
         let mut planning: Vec<Pos> = Vec::new();
         let mut rows_length = Vec::new();
         let mut last_len: u16 = 0;
-        let mut start_the_row = true;
         let mut right_side = 0;
 
         for (i, word) in bucket.words.iter().enumerate() {
-            let len = (*word).raw.len() as u16;
+            let len = word.width() as u16;
             let (gap, start_x, start_y): (u16, _, _);
 
             {
                 let last_pos = planning.last().unwrap_or(&self.origin);
-                gap = if start_the_row { 0 } else { sep };
+                gap = if planning.is_empty() { 0 } else { sep };
                 start_x = last_pos.x + last_len + gap;
-                start_y = last_pos.y;
+                start_y = match planning.is_empty() {
+                    true => last_pos.y + self.line_height - 1,
+                    false => last_pos.y
+                };
             }
 
             // check if this fit horizontally
@@ -290,23 +337,22 @@ impl Constraint {
                     // check if this fit vertically
                     match self.dim.height {
                         // if the new row overflows the constraint
-                        Measurement::Value(frame_height) if start_y + 1 - self.origin.y >= frame_height => {
+                        Measurement::Value(frame_height) if start_y + self.line_height - self.origin.y >= frame_height => {
                             return Err(LayoutError::TooManyWords(i))
                         }
                         // the word is now the starter of a new row
                         Measurement::Value(_) | Measurement::Infinite => {
                             Pos {
                                 x: self.origin.x,
-                                y: start_y + 1
+                                y: start_y + self.line_height
                             }
                         }
                     }
                 }
             };
 
-            start_the_row = pos.y != start_y;
-
-            if start_the_row {
+            // the word starts a new row
+            if pos.y != start_y {
                 let previous_row_length = start_x - gap;
                 rows_length.push(previous_row_length - self.origin.x);
                 right_side = max(right_side, previous_row_length);
@@ -320,7 +366,7 @@ impl Constraint {
         {
             let last_pos = planning.last().expect("not possible");
             rows_length.push(last_pos.x + last_len - self.origin.x);
-            bottom_line = last_pos.y - self.origin.y;
+            bottom_line = last_pos.y + 1 - self.origin.y;
         }
 
         Ok((planning, Dim { w: right_side, h: bottom_line }, rows_length))
@@ -336,10 +382,11 @@ mod test {
         let c = Constraint {
             origin: Pos { x: 0, y: 0 },
             dim: AdaptativeDim {
-                height: Measurement::Value(enough_height_for_all as u16),
-                width: Measurement::Value(5 as u16)
+                height: Measurement::Value(enough_height_for_all),
+                width: Measurement::Value(5)
             },
-            align: Alignment::top_left()
+            align: Alignment::top_left(),
+            line_height: 1
         };
         let input_bucket = Bucket::new(vec!["larger"]);
         let index_of_word_larger = 0;
@@ -354,10 +401,11 @@ mod test {
         let c = Constraint {
             origin: Pos { x: 0, y: 0 },
             dim: AdaptativeDim {
-                height: Measurement::Value(1 as u16),
-                width: Measurement::Value(enough_width_for_all as u16)
+                height: Measurement::Value(1),
+                width: Measurement::Value(enough_width_for_all)
             },
-            align: Alignment::top_left()
+            align: Alignment::top_left(),
+            line_height: 1
         };
         let input_bucket = Bucket::new(vec!["fit", "stalker"]);
         let index_of_word_stalker = 1;
@@ -379,10 +427,11 @@ mod test {
         let c = Constraint {
             origin: Pos { x: 0, y: 0 },
             dim: AdaptativeDim {
-                height: Measurement::Value(2 as u16),
-                width: Measurement::Value(width as u16)
+                height: Measurement::Value(2),
+                width: Measurement::Value(width)
             },
-            align: Alignment::top_left()
+            align: Alignment::top_left(),
+            line_height: 1
         };
         let expected_positions = vec![
             Pos { x: 0, y: 0 },
@@ -401,11 +450,12 @@ mod test {
         let c = Constraint {
             origin: Pos { x: 0, y: 0 },
             dim: AdaptativeDim {
-                height: Measurement::Value(1 as u16),
+                height: Measurement::Value(1),
                 // not relevant as long as not null
                 width: Measurement::Infinite
             },
-            align: Alignment::top_left()
+            align: Alignment::top_left(),
+            line_height: 1
         };
         let input_bucket = Bucket::new(vec!["first", "second", "third"]);
         let expected_positions = vec![Pos { x: 0, y: 0 }, Pos { x: 6, y: 0 }, Pos { x: 13, y: 0 }];
@@ -414,6 +464,47 @@ mod test {
         assert_eq!(final_positions, expected_positions);
     }
 
+    #[test]
+    fn measure_display_width() {
+        use super::*;
+        let c = Constraint {
+            origin: Pos { x: 0, y: 0 },
+            dim: AdaptativeDim {
+                height: Measurement::Value(3),
+                width: Measurement::Value(9)
+            },
+            align: Alignment::top_left(),
+            line_height: 1
+        };
+        // 4 and 3 columns, but 8 and 6 bytes
+        let input_bucket = Bucket::new(vec!["\u{e9}t\u{e9}s", "e\u{301}te\u{301}", "漢字", "«œ»"]);
+        let expected_positions = vec![Pos { x: 0, y: 0 }, Pos { x: 5, y: 0 }, Pos { x: 0, y: 1 }, Pos { x: 5, y: 1 }];
+
+        let final_positions = c.organize(&input_bucket).expect("positioning failed").0;
+        assert_eq!(final_positions, expected_positions);
+    }
+
+    #[test]
+    fn rows_of_several_lines() {
+        use super::*;
+        let c = Constraint {
+            origin: Pos { x: 0, y: 0 },
+            dim: AdaptativeDim {
+                height: Measurement::Value(4),
+                width: Measurement::Value(6)
+            },
+            align: Alignment::top_left(),
+            line_height: 2
+        };
+        let input_bucket = Bucket::new(vec!["first", "second", "third"]);
+
+        // the words on the last line of each row
+        let (positions, bbox) = c.organize(&Bucket::new(vec!["first", "second"])).expect("positioning failed");
+        assert_eq!(positions, vec![Pos { x: 0, y: 1 }, Pos { x: 0, y: 3 }]);
+        assert_eq!(bbox, BoundingBox { x: 0, y: 0, w: 6, h: 4 });
+        assert_eq!(c.organize(&input_bucket), Err(LayoutError::TooManyWords(2)));
+    }
+
     #[test]
     fn auto_add_rows() {
         use super::*;
@@ -421,9 +512,10 @@ mod test {
             origin: Pos { x: 0, y: 0 },
             dim: AdaptativeDim {
                 height: Measurement::Infinite,
-                width: Measurement::Value(6 as u16) // not relevant as long as minimal word len
+                width: Measurement::Value(6) // not relevant as long as minimal word len
             },
-            align: Alignment::top_left()
+            align: Alignment::top_left(),
+            line_height: 1
         };
         let input_bucket = Bucket::new(vec!["first", "second", "third"]);
         let expected_positions = vec![Pos { x: 0, y: 0 }, Pos { x: 0, y: 1 }, Pos { x: 0, y: 2 }];
@@ -432,6 +524,26 @@ mod test {
         assert_eq!(final_positions, expected_positions);
     }
 
+    #[test]
+    fn gap_after_row_starter() {
+        use super::*;
+        let c = Constraint {
+            origin: Pos { x: 0, y: 0 },
+            dim: AdaptativeDim {
+                height: Measurement::Infinite,
+                width: Measurement::Value(9)
+            },
+            align: Alignment::top_left(),
+            line_height: 1
+        };
+        // the second word of a row is apart from the first one
+        let input_bucket = Bucket::new(vec!["abcd", "efgh", "ij", "kl"]);
+        let expected_positions = vec![Pos { x: 0, y: 0 }, Pos { x: 5, y: 0 }, Pos { x: 0, y: 1 }, Pos { x: 3, y: 1 }];
+
+        let final_positions = c.organize(&input_bucket).expect("positioning failed").0;
+        assert_eq!(final_positions, expected_positions);
+    }
+
     #[test]
     fn center_content() {
         use super::*;
@@ -450,9 +562,10 @@ mod test {
             origin: Pos { x: 0, y: 0 },
             dim: AdaptativeDim {
                 height: Measurement::Infinite,
-                width: Measurement::Value(width as u16)
+                width: Measurement::Value(width)
             },
-            align: Alignment::centered()
+            align: Alignment::centered(),
+            line_height: 1
         };
         let expected_positions = vec![
             Pos { x: offset_first_line, y: 0 },
@@ -483,9 +596,10 @@ mod test {
             origin: Pos { x: 0, y: 0 },
             dim: AdaptativeDim {
                 height: Measurement::Infinite,
-                width: Measurement::Value(width as u16)
+                width: Measurement::Value(width)
             },
-            align: Alignment::bottom_right()
+            align: Alignment::bottom_right(),
+            line_height: 1
         };
         let expected_positions = vec![
             Pos { x: offset_first_line, y: 0 },
@@ -497,6 +611,68 @@ mod test {
         let final_positions = c.organize(&Bucket::new(words)).expect("positioning failed").0;
         assert_eq!(final_positions, expected_positions);
     }
+
+    #[test]
+    fn keep_room_for_keyboard() {
+        use super::*;
+        let c = Constraint {
+            origin: Pos { x: 1, y: 1 },
+            dim: AdaptativeDim {
+                height: Measurement::Value(10),
+                width: Measurement::Value(20)
+            },
+            align: Alignment::centered(),
+            line_height: 1
+        };
+        let keyboard = Dim { w: 10, h: 4 };
+
+        let layout = layout_with_keyboard(&c, &Bucket::new(vec!["first", "second", "third"]), &keyboard, 3)
+            .expect("positioning failed");
+
+        assert_eq!(layout.keyboard, Some(Pos { x: 6, y: 7 }));
+        let bottom = layout.frame.y + layout.frame.h - 1;
+        assert!(bottom + 3 <= 7, "words down to row {}", bottom);
+    }
+
+    #[test]
+    fn keyboard_too_large() {
+        use super::*;
+        let c = Constraint {
+            origin: Pos { x: 1, y: 1 },
+            dim: AdaptativeDim {
+                height: Measurement::Value(10),
+                width: Measurement::Value(20)
+            },
+            align: Alignment::centered(),
+            line_height: 1
+        };
+        let bucket = Bucket::new(vec!["first"]);
+
+        assert_eq!(layout_with_keyboard(&c, &bucket, &Dim { w: 21, h: 4 }, 3).err(), Some(LayoutError::NoRoomForKeyboard));
+        assert_eq!(layout_with_keyboard(&c, &bucket, &Dim { w: 10, h: 7 }, 3).err(), Some(LayoutError::NoRoomForKeyboard));
+        // a wider frame around the words
+        assert_eq!(layout_with_keyboard(&c, &bucket, &Dim { w: 10, h: 4 }, 6).err(), Some(LayoutError::NoRoomForKeyboard));
+    }
+
+    #[test]
+    fn alignment_by_name() {
+        use super::*;
+        for name in Alignment::names() {
+            assert!(Alignment::parse(name).is_some(), "{}", name);
+        }
+        let words = Bucket::new(vec!["first"]);
+        let c = Constraint {
+            origin: Pos { x: 1, y: 1 },
+            dim: AdaptativeDim {
+                height: Measurement::Value(3),
+                width: Measurement::Value(9)
+            },
+            align: Alignment::parse("bottom-left").unwrap(),
+            line_height: 1
+        };
+        assert_eq!(c.organize(&words).expect("positioning failed").0, vec![Pos { x: 1, y: 3 }]);
+        assert!(Alignment::parse("middle").is_none());
+    }
 }
 
 
@@ -505,37 +681,64 @@ mod test {
 pub struct Layout {
     pub frame: BoundingBox,
     // TODO keep it only visible inside the crate
-    pub positions: Vec<Pos> // TODO keep it only visible inside the crate
+    pub positions: Vec<Pos>, // TODO keep it only visible inside the crate
+    // top left corner of the keyboard diagram, if any
+    pub keyboard: Option<Pos>
 }
 
 pub fn layout(constraint: &Constraint, bucket: &Bucket) -> Result<Layout, LayoutError> {
-    let (poses, bbox) = try!(constraint.organize(bucket));
+    let (poses, bbox) = constraint.organize(bucket)?;
 
     debug_assert!(bbox.x >= constraint.origin.x,
-    format!("post-condition failed on x ({} >= {})", bbox.x, constraint.origin.x));
+    "post-condition failed on x ({} >= {})", bbox.x, constraint.origin.x);
     debug_assert!(bbox.y >= constraint.origin.y,
-    format!("post-condition failed on y ({} >= {})", bbox.y, constraint.origin.y));
-    match constraint.dim.width {
-        Measurement::Value(w) => {
-            debug_assert!(bbox.w <= w,
-            format!("post-condition failed on w ({} <= {})", bbox.w, w));
-        }
-        _ => {}
+    "post-condition failed on y ({} >= {})", bbox.y, constraint.origin.y);
+    if let Measurement::Value(w) = constraint.dim.width {
+        debug_assert!(bbox.w <= w,
+        "post-condition failed on w ({} <= {})", bbox.w, w);
     }
-    match constraint.dim.height {
-        Measurement::Value(h) => {
-            debug_assert!(bbox.h <= h,
-            format!("post-condition failed on h ({} <= {})", bbox.h, h));
-        }
-        _ => {}
+    if let Measurement::Value(h) = constraint.dim.height {
+        debug_assert!(bbox.h <= h,
+        "post-condition failed on h ({} <= {})", bbox.h, h);
     }
 
     Ok(Layout {
         frame: bbox,
-        positions: poses
+        positions: poses,
+        keyboard: None
     })
 }
 
+/// Layout the words above a keyboard diagram of the given size, which is
+/// centered at the bottom of the constraint, `gap` rows below the words to
+/// leave room for the frame around them.
+pub fn layout_with_keyboard(constraint: &Constraint, bucket: &Bucket, keyboard: &Dim,
+                            gap: u16) -> Result<Layout, LayoutError> {
+    let height = match constraint.dim.height {
+        Measurement::Value(height) if height > keyboard.h + gap => height,
+        _ => return Err(LayoutError::NoRoomForKeyboard)
+    };
+    let offset_x = match constraint.dim.width {
+        Measurement::Value(width) if width >= keyboard.w => (width - keyboard.w) / 2,
+        Measurement::Value(_) => return Err(LayoutError::NoRoomForKeyboard),
+        Measurement::Infinite => 0
+    };
+
+    let words_constraint = Constraint {
+        origin: constraint.origin.clone(),
+        dim: AdaptativeDim {
+            width: constraint.dim.width.clone(),
+            height: Measurement::Value(height - keyboard.h - gap)
+        },
+        align: constraint.align.clone(),
+        line_height: constraint.line_height
+    };
+
+    let mut layout = layout(&words_constraint, bucket)?;
+    layout.keyboard = Some(constraint.origin.shift(offset_x as i16, (height - keyboard.h) as i16));
+    Ok(layout)
+}
+
 
 //---
 #[macro_export]
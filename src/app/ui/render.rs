@@ -0,0 +1,418 @@
+use std::io;
+
+use termion::style;
+
+use app::analysis::Report;
+use app::engine::{Change, Engine};
+use app::stats::Summary;
+use app::ui::{BoundingBox, Layout};
+use app::ui::brush;
+use app::ui::feedback::Feedback;
+use app::ui::keyboard::Keyboard;
+use app::ui::theme::{Role, Theme};
+use app::word;
+
+
+//---
+/// Highlight of the char under the cursor, if the word holds it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cursor {
+    Absent,
+    Waiting,
+    // a dead key was typed, the char it applies to is awaited
+    Composing
+}
+
+fn cursor_of(engine: &Engine, word: usize) -> Cursor {
+    match (engine.cursor(), engine.composing()) {
+        (Some((current, _)), None) if current == word => Cursor::Waiting,
+        (Some((current, _)), Some(_)) if current == word => Cursor::Composing,
+        _ => Cursor::Absent
+    }
+}
+
+
+//---
+/// Paint every word in its current state, as after a new layout.
+pub fn write_session(layout: &Layout, engine: &Engine, feedback: &dyn Feedback, theme: &Theme,
+                     output: &mut dyn io::Write) -> io::Result<()> {
+    for (word, pos) in layout.positions.iter().enumerate() {
+        feedback.write_word(pos, engine.session().exercise(word), cursor_of(engine, word), theme, output)?;
+    }
+    Ok(())
+}
+
+/// Repaint the words affected by the changes, and the one holding the cursor.
+pub fn write_changes(layout: &Layout, engine: &Engine, changes: &[Change], feedback: &dyn Feedback, theme: &Theme,
+                     output: &mut dyn io::Write) -> io::Result<()> {
+    let cursor = engine.cursor().map(|(word, _)| word);
+
+    let mut touched: Vec<usize> = changes.iter().filter_map(Change::word).collect();
+    touched.extend(cursor);
+    touched.sort();
+    touched.dedup();
+
+    for word in touched {
+        feedback.write_word(&layout.positions[word], engine.session().exercise(word), cursor_of(engine, word), theme, output)?;
+    }
+    Ok(())
+}
+
+/// Draw the keyboard diagram, if the layout made room for it, with the
+/// keys of the char under the cursor.
+pub fn write_keyboard(layout: &Layout, keyboard: &Keyboard, engine: &Engine, theme: &Theme,
+                      output: &mut dyn io::Write) -> io::Result<()> {
+    let next = engine.next_char();
+    match layout.keyboard {
+        Some(ref pos) => keyboard.write(pos, next, theme, output),
+        None => Ok(())
+    }
+}
+
+
+//---
+fn summary_lines(summary: &Summary) -> Vec<String> {
+    let missed: Vec<String> = summary.missed.iter()
+        .map(|&(c, count)| format!("{} ({})", c, count))
+        .collect();
+    let slowest: Vec<String> = summary.slowest_bigrams.iter()
+        .map(|&(ref bigram, time)| format!("{} ({} ms)", bigram, time.as_millis()))
+        .collect();
+    let seconds = summary.duration.as_secs();
+
+    vec![
+        format!("Gross speed  {:.1} wpm", summary.gross_wpm),
+        format!("Net speed    {:.1} wpm", summary.net_wpm),
+        format!("Accuracy     {:.1} %", summary.accuracy * 100.0),
+        format!("Time         {}:{:02}", seconds / 60, seconds % 60),
+        format!("Most missed  {}", missed.join(", ")),
+        format!("Slowest      {}", slowest.join(", ")),
+        String::new(),
+        "Press any key to quit".to_string()
+    ]
+}
+
+/// Draw the summary of the session in a frame, starting where the frame
+/// of the words was, and grown to fit within the area, the lines being cut
+/// beyond. The screen is expected to be clear.
+pub fn write_summary(frame: &BoundingBox, area: &BoundingBox, gap: u16, summary: &Summary, theme: &Theme,
+                     output: &mut dyn io::Write) -> io::Result<()> {
+    let columns = (area.x + area.w).saturating_sub(frame.x) as usize;
+    let rows = (area.y + area.h).saturating_sub(frame.y) as usize;
+    let lines: Vec<String> = summary_lines(summary).iter()
+        .take(rows)
+        .map(|line| word::fit(line, columns))
+        .collect();
+    write_framed_lines(frame, gap, &lines, theme, output)
+}
+
+
+//---
+fn report_lines(report: &Report, count: usize) -> Vec<String> {
+    if report.chars.is_empty() {
+        return vec!["No session recorded yet".to_string()];
+    }
+
+    let mut lines = Vec::new();
+    for &(title, weaknesses) in [("Weakest characters", &report.chars), ("Weakest bigrams", &report.bigrams),
+                                 ("Weakest fingers", &report.fingers)].iter() {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(title.to_string());
+        for weakness in weaknesses.iter().take(count) {
+            let latency = match weakness.latency {
+                Some(latency) => format!("{} ms", latency.as_millis()),
+                None => "-".to_string()
+            };
+            lines.push(format!("  {:<12} {:>5.1} % missed, at least {:>5.1} %  {:>7}  {:>5} samples",
+                               weakness.item.to_string(), weakness.error_rate * 100.0,
+                               weakness.min_error_rate * 100.0, latency, weakness.samples));
+        }
+    }
+    if !report.unresolved.is_empty() {
+        lines.push(format!("  without the sessions on {}, layout not found", report.unresolved.join(", ")));
+    }
+    lines
+}
+
+/// Draw the `count` weakest items of each kind in a frame, at least as
+/// large as the given box. The screen is expected to be clear.
+pub fn write_report(frame: &BoundingBox, gap: u16, report: &Report, count: usize, theme: &Theme,
+                    output: &mut dyn io::Write) -> io::Result<()> {
+    write_framed_lines(frame, gap, &report_lines(report, count), theme, output)
+}
+
+
+//---
+/// Draw the frame `gap` cells around the box, in the style of the role.
+pub fn write_frame(bbox: &BoundingBox, gap: u16, role: Role, theme: &Theme,
+                   output: &mut dyn io::Write) -> io::Result<()> {
+    write!(output, "{}", theme.paint(role))?;
+    brush::write_frame2(bbox, gap, output)?;
+    write!(output, "{}", style::Reset)
+}
+
+fn write_framed_lines(frame: &BoundingBox, gap: u16, lines: &[String], theme: &Theme,
+                      output: &mut dyn io::Write) -> io::Result<()> {
+    let width = lines.iter().map(|l| word::width(l) as u16).max().unwrap_or(0);
+    let bbox = BoundingBox {
+        x: frame.x,
+        y: frame.y,
+        w: width.max(frame.w),
+        h: (lines.len() as u16).max(frame.h)
+    };
+
+    write_frame(&bbox, gap, Role::Frame, theme, output)?;
+    for (i, line) in lines.iter().enumerate() {
+        write!(output, "{}{}", bbox.pos().shift(0, i as i16), line)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use app::engine::{self, Input};
+    use app::exercise::ErrorPolicy;
+    use app::layout::compose::DeadKeys;
+    use app::session::WordPolicy;
+    use app::ui::{self, Alignment, Constraint, Measurement, AdaptativeDim, Pos};
+    use app::ui::feedback::background::Background;
+    use app::ui::screen::{Attrs, Color, Screen};
+    use app::word::Bucket;
+
+    use super::*;
+
+    const GREEN: Color = Color::Ansi(2);
+    const RED: Color = Color::Ansi(1);
+    const MAGENTA: Color = Color::Ansi(5);
+    const YELLOW: Color = Color::Ansi(3);
+
+    fn setup(words: Vec<&'static str>, policy: ErrorPolicy) -> (Layout, Engine, Screen) {
+        let bucket = Bucket::new(words);
+        let c = Constraint {
+            origin: Pos { x: 1, y: 1 },
+            dim: AdaptativeDim {
+                height: Measurement::Value(3),
+                width: Measurement::Value(10)
+            },
+            align: Alignment::top_left(),
+            line_height: 1
+        };
+        let layout = ui::layout(&c, &bucket).expect("positioning failed");
+        let engine = engine::new(&bucket, policy, WordPolicy::Lock);
+        let mut screen = Screen::new(10, 3);
+        write_session(&layout, &engine, &Background, &Theme::default(), &mut screen).unwrap();
+        (layout, engine, screen)
+    }
+
+    fn play(layout: &Layout, engine: &mut Engine, screen: &mut Screen, keys: &str) {
+        for c in keys.chars() {
+            let input = match c {
+                '\u{8}' => Input::Backspace,
+                c => Input::Char(c)
+            };
+            let changes = engine.feed(input);
+            write_changes(layout, engine, &changes, &Background, &Theme::default(), screen).unwrap();
+        }
+    }
+
+    #[test]
+    fn highlight_typed_chars() {
+        let (layout, mut engine, mut screen) = setup(vec!["abc", "de"], ErrorPolicy::Forward);
+        assert_eq!(screen.row(1), "abc de");
+        assert_eq!(screen.cell(1, 1).bg, MAGENTA);
+
+        play(&layout, &mut engine, &mut screen, "axcd");
+
+        assert_eq!(screen.row(1), "axc de");
+        assert_eq!(screen.cell(1, 1).bg, GREEN);
+        assert_eq!(screen.cell(2, 1).bg, RED);
+        assert_eq!(screen.cell(3, 1).bg, GREEN);
+        assert_eq!(screen.cell(4, 1).bg, Color::Reset);
+        assert_eq!(screen.cell(5, 1).bg, GREEN);
+        assert_eq!(screen.cell(6, 1).bg, MAGENTA);
+    }
+
+    #[test]
+    fn highlight_wide_and_accented_chars() {
+        let (layout, mut engine, mut screen) = setup(vec!["漢字", "e\u{301}t\u{e9}"], ErrorPolicy::Forward);
+        assert_eq!(screen.row(1), "漢字 \u{e9}t\u{e9}");
+
+        play(&layout, &mut engine, &mut screen, "漢x\u{e9}");
+
+        // the mistyped char is padded to the width of the expected one
+        assert_eq!(screen.row(1), "漢x  \u{e9}t\u{e9}");
+        assert_eq!(screen.cell(1, 1).bg, GREEN);
+        assert_eq!(screen.cell(3, 1).bg, RED);
+        assert_eq!(screen.cell(4, 1).bg, RED);
+        assert_eq!(screen.cell(6, 1).bg, GREEN);
+        assert_eq!(screen.cell(7, 1).bg, MAGENTA);
+    }
+
+    #[test]
+    fn highlight_dead_key() {
+        let (layout, engine, mut screen) = setup(vec!["ête"], ErrorPolicy::Block);
+        let mut engine = engine.with_dead_keys(DeadKeys::new(vec!['\u{302}']));
+
+        play(&layout, &mut engine, &mut screen, "^");
+        assert_eq!(screen.cell(1, 1).bg, YELLOW);
+        assert_eq!(engine.record().chars().len(), 0);
+
+        play(&layout, &mut engine, &mut screen, "e");
+        assert_eq!(screen.row(1), "ête");
+        assert_eq!(screen.cell(1, 1).bg, GREEN);
+        assert_eq!(screen.cell(2, 1).bg, MAGENTA);
+    }
+
+    #[test]
+    fn repaint_after_relayout() {
+        let (layout, mut engine, mut screen) = setup(vec!["abc", "de"], ErrorPolicy::Forward);
+        play(&layout, &mut engine, &mut screen, "axcd");
+
+        // narrower, a word on each row
+        let c = Constraint {
+            origin: Pos { x: 1, y: 1 },
+            dim: AdaptativeDim {
+                height: Measurement::Value(3),
+                width: Measurement::Value(4)
+            },
+            align: Alignment::top_left(),
+            line_height: 1
+        };
+        let bucket = Bucket::new(vec!["abc", "de"]);
+        let layout = ui::layout(&c, &bucket).expect("positioning failed");
+        let mut screen = Screen::new(4, 3);
+        write_session(&layout, &engine, &Background, &Theme::default(), &mut screen).unwrap();
+
+        assert_eq!(screen.row(1), "axc");
+        assert_eq!(screen.row(2), "de");
+        assert_eq!(screen.cell(1, 1).bg, GREEN);
+        assert_eq!(screen.cell(2, 1).bg, RED);
+        assert_eq!(screen.cell(1, 2).bg, GREEN);
+        assert_eq!(screen.cell(2, 2).bg, MAGENTA);
+    }
+
+    #[test]
+    fn highlight_without_colors() {
+        let (layout, mut engine, _) = setup(vec!["abc"], ErrorPolicy::Correctable);
+        let theme = Theme::preset("monochrome").unwrap();
+        let mut screen = Screen::new(10, 3);
+
+        for c in "ax\u{8}b".chars() {
+            let input = match c {
+                '\u{8}' => Input::Backspace,
+                c => Input::Char(c)
+            };
+            let changes = engine.feed(input);
+            write_changes(&layout, &engine, &changes, &Background, &theme, &mut screen).unwrap();
+        }
+
+        let attrs = |x| screen.cell(x, 1).attrs;
+        assert_eq!(attrs(1), Attrs { bold: true, underline: false, reverse: false });
+        assert_eq!(attrs(2), Attrs { bold: false, underline: true, reverse: false });
+        assert_eq!(attrs(3), Attrs { bold: false, underline: false, reverse: true });
+        assert!((1..4).all(|x| screen.cell(x, 1).bg == Color::Reset && screen.cell(x, 1).fg == Color::Reset));
+    }
+
+    #[test]
+    fn blocked_cursor_stays_on_char() {
+        let (layout, mut engine, mut screen) = setup(vec!["ab"], ErrorPolicy::Block);
+
+        play(&layout, &mut engine, &mut screen, "ax");
+
+        assert_eq!(screen.row(1), "ab");
+        assert_eq!(screen.cell(1, 1).bg, GREEN);
+        assert_eq!(screen.cell(2, 1).bg, MAGENTA);
+    }
+
+    #[test]
+    fn erased_char_is_pending_again() {
+        let (layout, mut engine, mut screen) = setup(vec!["ab"], ErrorPolicy::Correctable);
+
+        play(&layout, &mut engine, &mut screen, "x\u{8}");
+
+        assert_eq!(screen.row(1), "ab");
+        assert_eq!(screen.cell(1, 1).bg, MAGENTA);
+        assert_eq!(screen.cell(2, 1).bg, Color::Reset);
+    }
+
+    #[test]
+    fn summary_in_frame() {
+        use std::time::Duration;
+
+        let summary = Summary {
+            duration: Duration::from_secs(75),
+            keystrokes: 100,
+            errors: 3,
+            gross_wpm: 16.0,
+            net_wpm: 13.6,
+            accuracy: 0.97,
+            missed: vec![('e', 2), ('t', 1)],
+            slowest_bigrams: vec![("th".to_string(), Duration::from_millis(420))]
+        };
+        let mut screen = Screen::new(40, 14);
+        let area = BoundingBox { x: 4, y: 4, w: 34, h: 8 };
+        write_summary(&BoundingBox { x: 4, y: 4, w: 10, h: 1 }, &area, 2, &summary, &Theme::default(), &mut screen)
+            .unwrap();
+
+        assert!(screen.row(2).starts_with(" ┌───"));
+        assert_eq!(screen.row(4), " │ Gross speed  16.0 wpm     │");
+        assert_eq!(screen.text(4, 6, 20), "Accuracy     97.0 % ");
+        assert_eq!(screen.text(4, 7, 17), "Time         1:15");
+        assert_eq!(screen.text(4, 8, 26), "Most missed  e (2), t (1) ");
+        assert_eq!(screen.text(4, 9, 25), "Slowest      th (420 ms) ");
+        assert!(screen.row(13).starts_with(" └───"));
+    }
+
+    #[test]
+    fn summary_cut_to_area() {
+        use app::record::Record;
+        use app::stats;
+
+        let summary = stats::summarize(&Record::default());
+        let mut screen = Screen::new(20, 7);
+        let area = BoundingBox { x: 3, y: 3, w: 15, h: 2 };
+        write_summary(&BoundingBox { x: 3, y: 3, w: 4, h: 1 }, &area, 1, &summary, &Theme::default(), &mut screen)
+            .unwrap();
+
+        assert_eq!(screen.row(2), " ┌───────────────┐");
+        assert_eq!(screen.row(3), " │Gross speed  0.│");
+        assert_eq!(screen.row(4), " │Net speed    0.│");
+        assert_eq!(screen.row(5), " └───────────────┘");
+    }
+
+    #[test]
+    fn report_weakest_items() {
+        use std::time::Duration;
+
+        use app::analysis::{Item, Weakness};
+
+        let weakness = |item, error_rate, latency: Option<u64>| Weakness {
+            item,
+            samples: 40,
+            error_rate,
+            min_error_rate: error_rate / 2.0,
+            latency: latency.map(Duration::from_millis),
+            confidence: 0.8,
+            score: 0.0
+        };
+        let report = Report {
+            chars: vec![weakness(Item::Char('e'), 0.125, Some(180)), weakness(Item::Char('a'), 0.0, None)],
+            bigrams: vec![weakness(Item::Bigram('t', 'h'), 0.05, Some(420))],
+            fingers: Vec::new(),
+            unresolved: vec!["gone.xkb".to_string()]
+        };
+        let mut screen = Screen::new(80, 14);
+        write_report(&BoundingBox { x: 3, y: 3, w: 0, h: 0 }, 2, &report, 1, &Theme::default(), &mut screen).unwrap();
+
+        assert_eq!(screen.text(3, 3, 18), "Weakest characters");
+        assert_eq!(screen.text(3, 4, 71), "  e             12.5 % missed, at least   6.2 %   180 ms     40 samples");
+        assert_eq!(screen.text(3, 5, 5), "     ");
+        assert_eq!(screen.text(3, 6, 15), "Weakest bigrams");
+        assert_eq!(screen.text(3, 7, 5), "  th ");
+        assert_eq!(screen.text(3, 9, 15), "Weakest fingers");
+        assert_eq!(screen.text(3, 10, 52), "  without the sessions on gone.xkb, layout not found");
+        assert!(screen.row(12).trim_start().starts_with("└"));
+    }
+}
@@ -0,0 +1,407 @@
+use std::io;
+use std::str;
+
+use unicode_width::UnicodeWidthChar;
+
+
+// char of the cell covered by the right half of a wide char
+pub const CONTINUATION: char = '\0';
+
+
+//---
+/// Color of a cell, as set by the escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    Reset,
+    // 16 and 256 colors palette
+    Ansi(u8),
+    Rgb(u8, u8, u8)
+}
+
+
+//---
+/// Attributes of a cell, besides its colors.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Attrs {
+    pub bold: bool,
+    pub underline: bool,
+    pub reverse: bool
+}
+
+
+//---
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    pub ch: char,
+    pub fg: Color,
+    pub bg: Color,
+    pub attrs: Attrs
+}
+
+impl Cell {
+    fn blank() -> Cell {
+        Cell { ch: ' ', fg: Color::Reset, bg: Color::Reset, attrs: Attrs::default() }
+    }
+}
+
+
+//---
+/// In-memory terminal, understanding the subset of escape sequences
+/// written by the application.
+///
+/// Coordinates are 1-based, as in `termion::cursor::Goto`.
+#[derive(Debug)]
+pub struct Screen {
+    w: u16,
+    h: u16,
+    cells: Vec<Cell>,
+    x: u16,
+    y: u16,
+    fg: Color,
+    bg: Color,
+    attrs: Attrs,
+    cursor_visible: bool,
+    // combining marks written over each cell
+    marks: Vec<String>,
+    // bytes of an incomplete char or escape sequence
+    pending: Vec<u8>
+}
+
+impl Screen {
+    pub fn new(w: u16, h: u16) -> Screen {
+        Screen {
+            w,
+            h,
+            cells: vec![Cell::blank(); w as usize * h as usize],
+            x: 1,
+            y: 1,
+            fg: Color::Reset,
+            bg: Color::Reset,
+            attrs: Attrs::default(),
+            cursor_visible: true,
+            marks: vec![String::new(); w as usize * h as usize],
+            pending: Vec::new()
+        }
+    }
+
+    pub fn cell(&self, x: u16, y: u16) -> Cell {
+        assert!(x >= 1 && x <= self.w && y >= 1 && y <= self.h,
+                "cell ({}, {}) out of the screen", x, y);
+        self.cells[self.index(x, y)]
+    }
+
+    /// Text of a whole row, trailing blanks removed.
+    pub fn row(&self, y: u16) -> String {
+        self.text(1, y, self.w).trim_end().to_string()
+    }
+
+    /// Text of the cells from `x` on, `len` of them, with their combining
+    /// marks and a single char for each wide char.
+    pub fn text(&self, x: u16, y: u16, len: u16) -> String {
+        let mut text = String::new();
+        for x in x..x + len {
+            let ch = self.cell(x, y).ch;
+            if ch != CONTINUATION {
+                text.push(ch);
+                text.push_str(&self.marks[self.index(x, y)]);
+            }
+        }
+        text
+    }
+
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        (y - 1) as usize * self.w as usize + (x - 1) as usize
+    }
+
+    fn put(&mut self, ch: char) {
+        match (ch, ch.width().unwrap_or(0)) {
+            ('\n', _) => self.y += 1,
+            ('\r', _) => self.x = 1,
+            // combining mark, on the last written cell
+            (_, 0) => {
+                let x = match self.x.checked_sub(1).map(|x| (x, self.cell_at(x, self.y))) {
+                    Some((x, Some(cell))) if cell.ch == CONTINUATION => x - 1,
+                    Some((x, Some(_))) => x,
+                    _ => return
+                };
+                let i = self.index(x, self.y);
+                self.marks[i].push(ch);
+            }
+            (_, width) => {
+                let cell = Cell { ch, fg: self.fg, bg: self.bg, attrs: self.attrs };
+                self.set(self.x, cell);
+                if width == 2 {
+                    self.set(self.x + 1, Cell { ch: CONTINUATION, ..cell });
+                }
+                self.x += width as u16;
+            }
+        }
+    }
+
+    fn cell_at(&self, x: u16, y: u16) -> Option<Cell> {
+        match x >= 1 && x <= self.w && y >= 1 && y <= self.h {
+            true => Some(self.cells[self.index(x, y)]),
+            false => None
+        }
+    }
+
+    // write a cell, blanking the other half of the wide chars it covers
+    fn set(&mut self, x: u16, cell: Cell) {
+        let y = self.y;
+        let previous = match self.cell_at(x, y) {
+            Some(previous) => previous,
+            None => return
+        };
+        if previous.ch == CONTINUATION && cell.ch != CONTINUATION {
+            self.blank(x - 1, y);
+        }
+        if self.cell_at(x + 1, y).is_some_and(|next| next.ch == CONTINUATION) {
+            self.blank(x + 1, y);
+        }
+
+        let i = self.index(x, y);
+        self.cells[i] = cell;
+        self.marks[i].clear();
+    }
+
+    fn blank(&mut self, x: u16, y: u16) {
+        let i = self.index(x, y);
+        self.cells[i].ch = ' ';
+        self.marks[i].clear();
+    }
+
+    fn clear(&mut self) {
+        for cell in self.cells.iter_mut() {
+            *cell = Cell::blank();
+        }
+        for marks in self.marks.iter_mut() {
+            marks.clear();
+        }
+    }
+
+    // apply a CSI sequence, given its parameters and final byte
+    fn control(&mut self, params: &str, action: char) -> io::Result<()> {
+        let values = |params: &str| -> io::Result<Vec<u16>> {
+            params.split(';')
+                .filter(|p| !p.is_empty())
+                .map(|p| p.parse::<u16>().map_err(|_| invalid(params)))
+                .collect()
+        };
+
+        match action {
+            'H' => {
+                let v = values(params)?;
+                self.y = *v.first().unwrap_or(&1);
+                self.x = *v.get(1).unwrap_or(&1);
+            }
+            'J' if params == "2" => self.clear(),
+            'h' if params == "?25" => self.cursor_visible = true,
+            'l' if params == "?25" => self.cursor_visible = false,
+            'm' => {
+                let v = values(params)?;
+                self.graphic_rendition(&v)?;
+            }
+            _ => return Err(invalid(params))
+        }
+        Ok(())
+    }
+
+    fn graphic_rendition(&mut self, v: &[u16]) -> io::Result<()> {
+        // extended color, and the parameters after it
+        let color = |v: &[u16]| -> io::Result<(Color, usize)> {
+            match *v {
+                [5, n, ..] => Ok((Color::Ansi(n as u8), 2)),
+                [2, r, g, b, ..] => Ok((Color::Rgb(r as u8, g as u8, b as u8), 4)),
+                _ => Err(invalid(&format!("{:?}", v)))
+            }
+        };
+
+        if v.is_empty() {
+            return self.graphic_rendition(&[0]);
+        }
+        let mut i = 0;
+        while i < v.len() {
+            match v[i] {
+                0 => {
+                    self.fg = Color::Reset;
+                    self.bg = Color::Reset;
+                    self.attrs = Attrs::default();
+                }
+                1 => self.attrs.bold = true,
+                4 => self.attrs.underline = true,
+                7 => self.attrs.reverse = true,
+                22 => self.attrs.bold = false,
+                24 => self.attrs.underline = false,
+                27 => self.attrs.reverse = false,
+                n @ 30..=37 => self.fg = Color::Ansi((n - 30) as u8),
+                n @ 40..=47 => self.bg = Color::Ansi((n - 40) as u8),
+                n @ 90..=97 => self.fg = Color::Ansi((n - 90 + 8) as u8),
+                n @ 100..=107 => self.bg = Color::Ansi((n - 100 + 8) as u8),
+                39 => self.fg = Color::Reset,
+                49 => self.bg = Color::Reset,
+                38 => {
+                    let (fg, len) = color(&v[i + 1..])?;
+                    self.fg = fg;
+                    i += len;
+                }
+                48 => {
+                    let (bg, len) = color(&v[i + 1..])?;
+                    self.bg = bg;
+                    i += len;
+                }
+                _ => return Err(invalid(&format!("{:?}", v)))
+            }
+            i += 1;
+        }
+        Ok(())
+    }
+
+    // consume as much of the pending bytes as possible
+    fn process(&mut self) -> io::Result<()> {
+        let mut start = 0;
+        while start < self.pending.len() {
+            let rest = &self.pending[start..];
+
+            if rest[0] == 0x1b {
+                if rest.len() < 2 {
+                    break;
+                }
+                if rest[1] != b'[' {
+                    return Err(invalid("unknown escape sequence"));
+                }
+                let end = match rest.iter().skip(2).position(|b| (0x40..0x7f).contains(b)) {
+                    Some(end) => end + 2,
+                    None => break
+                };
+                let params = str::from_utf8(&rest[2..end])
+                    .map_err(|_| invalid("non utf-8 escape sequence"))?
+                    .to_string();
+                let action = rest[end] as char;
+                self.control(&params, action)?;
+                start += end + 1;
+                continue;
+            }
+
+            let len = match rest[0] {
+                0x00..=0x7f => 1,
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                _ => 4
+            };
+            if rest.len() < len {
+                break;
+            }
+            let ch = str::from_utf8(&rest[..len])
+                .map_err(|_| invalid("non utf-8 output"))?
+                .chars().next().expect("not possible");
+            self.put(ch);
+            start += len;
+        }
+
+        self.pending.drain(..start);
+        Ok(())
+    }
+}
+
+fn invalid(what: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("unsupported sequence: {}", what))
+}
+
+impl io::Write for Screen {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        self.process()?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use termion;
+    use termion::color;
+
+    use super::*;
+
+    #[test]
+    fn write_at_position() {
+        let mut screen = Screen::new(10, 3);
+        write!(screen, "{}ab{}c", termion::cursor::Goto(2, 2), termion::cursor::Goto(9, 3)).unwrap();
+
+        assert_eq!(screen.row(1), "");
+        assert_eq!(screen.row(2), " ab");
+        assert_eq!(screen.text(9, 3, 2), "c ");
+    }
+
+    #[test]
+    fn keep_colors() {
+        let mut screen = Screen::new(4, 1);
+        write!(screen, "{}{}a{}b{}{}c",
+               color::Bg(color::Red), color::Fg(color::Rgb(1, 2, 3)),
+               color::Bg(color::AnsiValue(200)),
+               color::Bg(color::Reset), color::Fg(color::Reset)).unwrap();
+
+        let attrs = Attrs::default();
+        assert_eq!(screen.cell(1, 1), Cell { ch: 'a', fg: Color::Rgb(1, 2, 3), bg: Color::Ansi(1), attrs });
+        assert_eq!(screen.cell(2, 1), Cell { ch: 'b', fg: Color::Rgb(1, 2, 3), bg: Color::Ansi(200), attrs });
+        assert_eq!(screen.cell(3, 1), Cell { ch: 'c', fg: Color::Reset, bg: Color::Reset, attrs });
+    }
+
+    #[test]
+    fn combined_attributes() {
+        let mut screen = Screen::new(4, 1);
+        write!(screen, "\x1b[0;1;7;32;104ma\x1b[22;27;4;38;5;208mb{}c", termion::style::Reset).unwrap();
+
+        assert_eq!(screen.cell(1, 1).fg, Color::Ansi(2));
+        assert_eq!(screen.cell(1, 1).bg, Color::Ansi(12));
+        assert_eq!(screen.cell(1, 1).attrs, Attrs { bold: true, underline: false, reverse: true });
+        assert_eq!(screen.cell(2, 1).fg, Color::Ansi(208));
+        assert_eq!(screen.cell(2, 1).bg, Color::Ansi(12));
+        assert_eq!(screen.cell(2, 1).attrs, Attrs { bold: false, underline: true, reverse: false });
+        assert_eq!(screen.cell(3, 1), Cell { ch: 'c', ..Cell::blank() });
+    }
+
+    #[test]
+    fn split_sequences_and_chars() {
+        let mut screen = Screen::new(4, 1);
+        let bytes = format!("{}é", termion::cursor::Goto(3, 1)).into_bytes();
+        for b in bytes.iter() {
+            screen.write_all(&[*b]).unwrap();
+        }
+
+        assert_eq!(screen.cell(3, 1).ch, 'é');
+    }
+
+    #[test]
+    fn clear_and_cursor() {
+        let mut screen = Screen::new(2, 1);
+        write!(screen, "ab{}{}", termion::clear::All, termion::cursor::Hide).unwrap();
+
+        assert_eq!(screen.row(1), "");
+        assert!(!screen.cursor_visible());
+    }
+
+    #[test]
+    fn wide_chars_and_marks() {
+        let mut screen = Screen::new(6, 1);
+        write!(screen, "漢a\u{301}字").unwrap();
+
+        assert_eq!(screen.row(1), "漢a\u{301}字");
+        assert_eq!(screen.cell(2, 1).ch, CONTINUATION);
+        assert_eq!(screen.cell(3, 1).ch, 'a');
+        assert_eq!(screen.text(4, 1, 2), "字");
+
+        // overwriting half of a wide char blanks the other half
+        write!(screen, "{}b", termion::cursor::Goto(2, 1)).unwrap();
+        assert_eq!(screen.row(1), " ba\u{301}字");
+    }
+}
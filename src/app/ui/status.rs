@@ -0,0 +1,204 @@
+use std::io;
+use std::time::Duration;
+
+use termion::style;
+
+use app::record::Record;
+use app::stats;
+use app::ui::Pos;
+use app::ui::theme::{Role, Theme};
+use app::word;
+
+
+//---
+/// Importance of a message, which also tells how long it stays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    Info,
+    Warning,
+    Error
+}
+
+impl Level {
+    fn timeout(&self) -> Duration {
+        match *self {
+            Level::Info => Duration::from_secs(3),
+            Level::Warning => Duration::from_secs(5),
+            Level::Error => Duration::from_secs(10)
+        }
+    }
+}
+
+
+//---
+#[derive(Debug, Clone, PartialEq)]
+struct Message {
+    level: Level,
+    text: String,
+    // session time after which it is gone
+    until: Duration
+}
+
+
+//---
+/// Bottom row of the terminal: the last message, or a label when there is
+/// none, on the left, then the live speed, accuracy and time on the right.
+///
+/// Times are durations since the start of the session, as in the record.
+#[derive(Debug)]
+pub struct StatusBar {
+    pos: Pos,
+    width: u16,
+    label: String,
+    message: Option<Message>,
+    // net speed and accuracy, once something was typed
+    speed: Option<(f64, f64)>,
+    elapsed: Duration
+}
+
+pub fn new(pos: Pos, width: u16) -> StatusBar {
+    StatusBar {
+        pos,
+        width,
+        label: String::new(),
+        message: None,
+        speed: None,
+        elapsed: Duration::from_secs(0)
+    }
+}
+
+impl StatusBar {
+    /// Move the bar, after the terminal was resized.
+    pub fn place(&mut self, pos: Pos, width: u16) {
+        self.pos = pos;
+        self.width = width;
+    }
+
+    /// Text shown when there is no message.
+    pub fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    /// Show a message, replacing the previous one.
+    pub fn post(&mut self, level: Level, text: &str, at: Duration) {
+        self.message = Some(Message { level, text: text.to_string(), until: at + level.timeout() });
+    }
+
+    /// Follow the record up to `at`, dropping the expired message, and tell
+    /// whether the bar needs to be written again.
+    pub fn update(&mut self, record: &Record, at: Duration) -> bool {
+        let before = (self.fields(), self.message.clone());
+
+        if self.message.as_ref().is_some_and(|m| m.until <= at) {
+            self.message = None;
+        }
+
+        let keystrokes = record.chars();
+        let errors = keystrokes.iter().filter(|k| !k.is_correct()).count();
+        self.elapsed = record.keystrokes().first().map_or(Duration::from_secs(0), |first| at.saturating_sub(first.at));
+        self.speed = match keystrokes.len() {
+            0 => None,
+            n => Some((stats::net_wpm(n, errors, self.elapsed), stats::accuracy(n, errors)))
+        };
+
+        before != (self.fields(), self.message.clone())
+    }
+
+    /// Write the whole row, blanking what was there before.
+    pub fn write(&self, theme: &Theme, output: &mut dyn io::Write) -> io::Result<()> {
+        // the last column is left blank, so that the terminal never scrolls,
+        // and the figures are dropped from a bar too narrow for them
+        let columns = (self.width as usize).saturating_sub(1);
+        let fields = match self.fields() {
+            fields if word::width(&fields) < columns => fields,
+            _ => String::new()
+        };
+        // a space at least between the text and the figures
+        let room = match fields.is_empty() {
+            true => columns,
+            false => columns - word::width(&fields) - 1
+        };
+
+        let (role, text) = match self.message {
+            Some(Message { level: Level::Info, ref text, .. }) => (Role::Status, text),
+            Some(Message { level: Level::Warning, ref text, .. }) => (Role::Warning, text),
+            Some(Message { level: Level::Error, ref text, .. }) => (Role::Error, text),
+            None => (Role::Status, &self.label)
+        };
+        let text = word::fit(text, room);
+        let padding = columns - word::width(&text) - word::width(&fields);
+
+        write!(output, "{}{}{}{}{}{}{}", self.pos, theme.paint(role), text,
+               theme.paint(Role::Status), " ".repeat(padding), fields, style::Reset)
+    }
+
+    fn fields(&self) -> String {
+        let time = format!("{}:{:02}", self.elapsed.as_secs() / 60, self.elapsed.as_secs() % 60);
+        match self.speed {
+            Some((wpm, accuracy)) => format!("{:.0} wpm  {:.0} %  {}", wpm, accuracy * 100.0, time),
+            None => time
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::record::{Keystroke, Kind};
+    use app::ui::screen::{Color, Screen};
+
+    fn bar() -> StatusBar {
+        new(Pos { x: 1, y: 2 }, 30)
+    }
+
+    #[test]
+    fn messages_expire() {
+        let mut status = bar();
+        let record = Record::default();
+        status.set_label("Lesson");
+        status.post(Level::Warning, "Unsupported event", Duration::from_secs(1));
+
+        let mut screen = Screen::new(30, 2);
+        status.write(&Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.row(2), "Unsupported event        0:00");
+        assert_eq!(screen.cell(1, 2).fg, Color::Ansi(3));
+
+        assert!(!status.update(&record, Duration::from_secs(5)));
+        assert!(status.update(&record, Duration::from_secs(6)));
+        status.write(&Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.row(2), "Lesson                   0:00");
+    }
+
+    #[test]
+    fn live_figures() {
+        let mut status = bar();
+        let mut record = Record::default();
+        for i in 0..10 {
+            let typed = match i {
+                4 => 'x',
+                _ => 'a'
+            };
+            record.push(Keystroke { at: Duration::from_secs(2 + i), kind: Kind::Char, expected: 'a', typed, word: 0,
+                                    offset: i as usize });
+        }
+
+        assert!(status.update(&record, Duration::from_secs(14)));
+        status.post(Level::Error, "A message too long for the bar", Duration::from_secs(14));
+        let mut screen = Screen::new(30, 2);
+        status.write(&Theme::default(), &mut screen).unwrap();
+        // 10 keystrokes in 12 seconds, one of them wrong
+        assert_eq!(screen.row(2), "A message t 5 wpm  90 %  0:12");
+        assert_eq!(screen.cell(30, 2).ch, ' ');
+
+        // the clock goes on without keystrokes
+        assert!(!status.update(&record, Duration::from_millis(14500)));
+        assert!(status.update(&record, Duration::from_secs(15)));
+
+        // too narrow for the figures, which are dropped
+        status.place(Pos { x: 1, y: 2 }, 12);
+        let mut screen = Screen::new(30, 2);
+        status.write(&Theme::default(), &mut screen).unwrap();
+        assert_eq!(screen.row(2), "A message t");
+        assert_eq!(screen.cell(12, 2).ch, ' ');
+    }
+}
@@ -0,0 +1,152 @@
+use std::io::{self, Write};
+use std::mem;
+use std::panic;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use libc;
+use termion::{clear, cursor, style};
+use termion::raw::{IntoRawMode, RawTerminal};
+
+use app::ui::Pos;
+
+
+// settings of the terminal before the session, while it runs, for the
+// panic hook which runs before the session is dropped
+static SAVED: Mutex<Option<libc::termios>> = Mutex::new(None);
+
+// last signal asking to stop, 0 if none
+static SIGNAL: AtomicI32 = AtomicI32::new(0);
+
+// message of the last panic, with its location
+static PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+
+//---
+/// The terminal in raw mode, cleared and with the cursor hidden, until
+/// the session is dropped, or `restore` is called on panic.
+pub struct Session {
+    // put back in cooked mode when dropped
+    output: RawTerminal<io::Stdout>
+}
+
+pub fn start() -> io::Result<Session> {
+    // termion keeps its own copy of the settings, out of reach of the hook
+    let mut termios: libc::termios = unsafe { mem::zeroed() };
+    if unsafe { libc::tcgetattr(libc::STDOUT_FILENO, &mut termios) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let output = io::stdout().into_raw_mode()?;
+    *SAVED.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(termios);
+
+    let mut session = Session { output };
+    write!(session, "{}{}{}{}", clear::All, Pos { x: 1, y: 1 }, style::Reset, cursor::Hide)?;
+    Ok(session)
+}
+
+impl Session {
+    /// End the session, leaving the cursor on the line after `pos` for the
+    /// shell.
+    pub fn close(mut self, pos: Pos) -> io::Result<()> {
+        writeln!(self, "{}{}{}", pos, cursor::Show, style::Reset)?;
+        self.flush()?;
+        if let Some(termios) = take_saved() {
+            put_back(&termios);
+        }
+        Ok(())
+    }
+}
+
+impl Write for Session {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.output.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.output.flush()
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        restore();
+    }
+}
+
+/// Put back the colors, the cursor and the settings of the terminal, if a
+/// session runs, the cursor going on the bottom line, cleared for a
+/// message. Later calls do nothing.
+pub fn restore() {
+    if let Some(termios) = take_saved() {
+        let mut output = io::stdout();
+        let _ = write!(output, "{}{}\r{}{}", style::Reset, cursor::Show, cursor::Down(u16::MAX), clear::CurrentLine);
+        let _ = output.flush();
+        put_back(&termios);
+    }
+}
+
+fn take_saved() -> Option<libc::termios> {
+    SAVED.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take()
+}
+
+fn put_back(termios: &libc::termios) {
+    unsafe { libc::tcsetattr(libc::STDOUT_FILENO, libc::TCSANOW, termios) };
+}
+
+
+//---
+extern "C" fn on_signal(signal: libc::c_int) {
+    // nothing else is safe in a handler, the session stops on its next poll
+    SIGNAL.store(signal, Ordering::SeqCst);
+}
+
+/// Restore the terminal on panic, keeping the message for `panic_message`
+/// instead of writing it over the session, and note SIGINT and SIGTERM
+/// for `interruption`.
+pub fn install_hooks() {
+    panic::set_hook(Box::new(|info| {
+        restore();
+        let message = info.payload().downcast_ref::<&str>().map(|text| text.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panic".to_string());
+        let message = match info.location() {
+            Some(location) => format!("{} ({})", message, location),
+            None => message
+        };
+        *PANIC.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(message);
+    }));
+    catch_signals();
+}
+
+fn catch_signals() {
+    for &signal in [libc::SIGINT, libc::SIGTERM].iter() {
+        unsafe { libc::signal(signal, on_signal as *const () as libc::sighandler_t) };
+    }
+}
+
+/// Signal received since the hooks were installed, if any.
+pub fn interruption() -> Option<i32> {
+    match SIGNAL.load(Ordering::SeqCst) {
+        0 => None,
+        signal => Some(signal)
+    }
+}
+
+/// Message of the last panic, taken once.
+pub fn panic_message() -> Option<String> {
+    PANIC.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn signal_noted() {
+        // the handler is called as the signal would, without being installed
+        assert_eq!(interruption(), None);
+        on_signal(libc::SIGTERM);
+        assert_eq!(interruption(), Some(libc::SIGTERM));
+        SIGNAL.store(0, Ordering::SeqCst);
+    }
+}
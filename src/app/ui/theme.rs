@@ -0,0 +1,539 @@
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use toml;
+
+use app::config;
+
+
+// the 16 colors palette as xterm shows it, to find the nearest one
+static PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0), (205, 0, 0), (0, 205, 0), (205, 205, 0),
+    (0, 0, 238), (205, 0, 205), (0, 205, 205), (229, 229, 229),
+    (127, 127, 127), (255, 0, 0), (0, 255, 0), (255, 255, 0),
+    (92, 92, 255), (255, 0, 255), (0, 255, 255), (255, 255, 255)
+];
+
+static NAMES: [&str; 16] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    "bright-black", "bright-red", "bright-green", "bright-yellow",
+    "bright-blue", "bright-magenta", "bright-cyan", "bright-white"
+];
+
+// levels of each component in the 6x6x6 cube of the 256 colors palette
+static CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+static PRESETS: [&str; 4] = ["default", "colorblind", "tritan", "monochrome"];
+
+// names of the roles in a theme file, in the order of `Role`
+static ROLES: [(Role, &str); 10] = [
+    (Role::Pending, "pending"),
+    (Role::Current, "current"),
+    (Role::Composing, "composing"),
+    (Role::Correct, "correct"),
+    (Role::Incorrect, "incorrect"),
+    (Role::Corrected, "corrected"),
+    (Role::Frame, "frame"),
+    (Role::Status, "status"),
+    (Role::Warning, "warning"),
+    (Role::Error, "error")
+];
+
+
+//---
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(io::Error),
+    // message of the toml parser
+    Syntax(String),
+    // key of the file, and the value which cannot be understood
+    Invalid(String, String)
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ThemeError::Io(ref err) => write!(f, "cannot access theme: {}", err),
+            ThemeError::Syntax(ref message) => write!(f, "theme is malformed: {}", message),
+            ThemeError::Invalid(ref key, ref value) => write!(f, "theme has an invalid {}: {}", key, value)
+        }
+    }
+}
+
+impl error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ThemeError::Io(ref err) => Some(err),
+            ThemeError::Syntax(_) | ThemeError::Invalid(_, _) => None
+        }
+    }
+}
+
+impl From<io::Error> for ThemeError {
+    fn from(err: io::Error) -> ThemeError {
+        ThemeError::Io(err)
+    }
+}
+
+
+//---
+/// Colors a terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Depth {
+    Ansi16,
+    Ansi256,
+    TrueColor
+}
+
+impl Depth {
+    /// Guess from the environment, as most terminals tell it: `COLORTERM`
+    /// for truecolor, `TERM` for 256 colors.
+    pub fn detect() -> Depth {
+        Depth::guess(env::var("COLORTERM").ok().as_deref(), env::var("TERM").ok().as_deref())
+    }
+
+    fn guess(colorterm: Option<&str>, term: Option<&str>) -> Depth {
+        match (colorterm, term) {
+            (Some("truecolor"), _) | (Some("24bit"), _) => Depth::TrueColor,
+            (_, Some(term)) if term.contains("256color") => Depth::Ansi256,
+            _ => Depth::Ansi16
+        }
+    }
+
+    fn parse(text: &str) -> Option<Depth> {
+        match text {
+            "16" => Some(Depth::Ansi16),
+            "256" => Some(Depth::Ansi256),
+            "truecolor" => Some(Depth::TrueColor),
+            _ => None
+        }
+    }
+}
+
+
+//---
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    // 16 colors palette, the bright ones last
+    Ansi(u8),
+    // 256 colors palette
+    Indexed(u8),
+    Rgb(u8, u8, u8)
+}
+
+impl Color {
+    /// Read a color name, a `#rrggbb` color, or an index in the 256 colors
+    /// palette.
+    pub fn parse(text: &str) -> Option<Color> {
+        if let Some(hex) = text.strip_prefix('#') {
+            if hex.len() != 6 || !hex.is_ascii() {
+                return None;
+            }
+            let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+            return Some(Color::Rgb(component(0)?, component(2)?, component(4)?));
+        }
+        if let Ok(index) = text.parse::<u8>() {
+            return Some(Color::Indexed(index));
+        }
+        NAMES.iter().position(|&name| name == text).map(|i| Color::Ansi(i as u8))
+    }
+
+    /// Nearest color the terminal can show.
+    pub fn downgrade(self, depth: Depth) -> Color {
+        match (self, depth) {
+            (Color::Indexed(n), _) if n < 16 => Color::Ansi(n),
+            (Color::Ansi(_), _) | (_, Depth::TrueColor) | (Color::Indexed(_), Depth::Ansi256) => self,
+            (Color::Rgb(r, g, b), Depth::Ansi256) => Color::Indexed(16 + 36 * level(r) + 6 * level(g) + level(b)),
+            (color, Depth::Ansi16) => {
+                let rgb = color.rgb();
+                let nearest = (0..PALETTE.len()).min_by_key(|&i| distance(PALETTE[i], rgb)).unwrap_or(0);
+                Color::Ansi(nearest as u8)
+            }
+        }
+    }
+
+    fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Ansi(n) => PALETTE[n as usize % PALETTE.len()],
+            Color::Indexed(n) if n < 16 => PALETTE[n as usize],
+            Color::Indexed(n) if n < 232 => {
+                let n = (n - 16) as usize;
+                (CUBE[n / 36], CUBE[n / 6 % 6], CUBE[n % 6])
+            }
+            // gray ramp
+            Color::Indexed(n) => {
+                let gray = 8 + 10 * (n - 232);
+                (gray, gray, gray)
+            }
+            Color::Rgb(r, g, b) => (r, g, b)
+        }
+    }
+
+    /// Parameters of the SGR sequence, for the foreground (`base` 30) or
+    /// the background (`base` 40).
+    fn sgr(self, base: u8) -> String {
+        match self {
+            Color::Ansi(n) if n < 8 => format!("{}", base + n),
+            Color::Ansi(n) => format!("{}", base + 60 + n % 16 - 8),
+            Color::Indexed(n) => format!("{};5;{}", base + 8, n),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b)
+        }
+    }
+}
+
+// index of the nearest level of the cube
+fn level(component: u8) -> u8 {
+    (0..CUBE.len())
+        .min_by_key(|&i| (i16::from(CUBE[i]) - i16::from(component)).abs())
+        .unwrap_or(0) as u8
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let square = |x: u8, y: u8| (i32::from(x) - i32::from(y)).pow(2) as u32;
+    square(a.0, b.0) + square(a.1, b.1) + square(a.2, b.2)
+}
+
+
+//---
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Style {
+    // the terminal default when none
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub underline: bool,
+    pub reverse: bool
+}
+
+fn plain() -> Style {
+    Style::default()
+}
+
+fn fg(color: Color) -> Style {
+    Style { fg: Some(color), ..Style::default() }
+}
+
+fn bg(color: Color) -> Style {
+    Style { bg: Some(color), ..Style::default() }
+}
+
+fn on(fg: Color, bg: Color) -> Style {
+    Style { fg: Some(fg), bg: Some(bg), ..Style::default() }
+}
+
+fn attrs(bold: bool, underline: bool, reverse: bool) -> Style {
+    Style { bold, underline, reverse, ..Style::default() }
+}
+
+
+//---
+/// What a styled text shows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Role {
+    // chars not typed yet
+    Pending,
+    // char under the cursor, and the keys to type it
+    Current,
+    // char under the cursor, after a dead key
+    Composing,
+    Correct,
+    Incorrect,
+    // right after a mistake
+    Corrected,
+    Frame,
+    Status,
+    // messages of the status bar
+    Warning,
+    Error
+}
+
+
+//---
+/// Style of each role, and the colors of the terminal they are shown on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub depth: Depth,
+    // in the order of `Role`
+    styles: [Style; 10]
+}
+
+/// `theme.toml` in the config directory.
+pub fn default_path() -> Option<PathBuf> {
+    config::config_dir().map(|dir| dir.join("theme.toml"))
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::preset("default").expect("missing default theme")
+    }
+}
+
+impl Theme {
+    pub fn preset_names() -> &'static [&'static str] {
+        &PRESETS
+    }
+
+    /// A builtin theme. The default and monochrome ones keep to the 16
+    /// colors of any terminal, colorblind and tritan use RGB colors, which
+    /// are brought down to the depth of the terminal when painted.
+    pub fn preset(name: &str) -> Option<Theme> {
+        let black = Color::Ansi(0);
+        let white = Color::Ansi(15);
+        let styles = match name {
+            "default" => [
+                plain(),
+                bg(Color::Ansi(5)),
+                bg(Color::Ansi(3)),
+                bg(Color::Ansi(2)),
+                bg(Color::Ansi(1)),
+                bg(Color::Ansi(2)),
+                plain(),
+                plain(),
+                fg(Color::Ansi(3)),
+                fg(Color::Ansi(1))
+            ],
+            // Okabe-Ito colors, blue against vermillion, told apart with
+            // the most common red-green color blindness
+            "colorblind" => [
+                plain(),
+                on(black, Color::Rgb(204, 121, 167)),
+                on(black, Color::Rgb(240, 228, 66)),
+                on(white, Color::Rgb(0, 114, 178)),
+                on(white, Color::Rgb(213, 94, 0)),
+                on(black, Color::Rgb(86, 180, 233)),
+                plain(),
+                plain(),
+                fg(Color::Rgb(230, 159, 0)),
+                fg(Color::Rgb(213, 94, 0))
+            ],
+            // bluish green against vermillion, without blue and yellow
+            // which tritanopia mixes up
+            "tritan" => [
+                plain(),
+                attrs(false, false, true),
+                attrs(false, true, true),
+                on(black, Color::Rgb(0, 158, 115)),
+                on(white, Color::Rgb(213, 94, 0)),
+                Style { underline: true, ..on(black, Color::Rgb(0, 158, 115)) },
+                plain(),
+                plain(),
+                attrs(true, false, false),
+                Style { bold: true, ..fg(Color::Rgb(213, 94, 0)) }
+            ],
+            // no color at all
+            "monochrome" => [
+                plain(),
+                attrs(false, false, true),
+                attrs(false, true, true),
+                attrs(true, false, false),
+                attrs(true, true, true),
+                attrs(false, true, false),
+                plain(),
+                attrs(false, false, true),
+                attrs(true, false, false),
+                attrs(true, true, false)
+            ],
+            _ => return None
+        };
+        Some(Theme { depth: Depth::Ansi16, styles })
+    }
+
+    pub fn with_depth(mut self, depth: Depth) -> Theme {
+        self.depth = depth;
+        self
+    }
+
+    pub fn style(&self, role: Role) -> Style {
+        self.styles[role as usize]
+    }
+
+    /// Escape sequence to write a text of this role.
+    pub fn paint(&self, role: Role) -> Paint {
+        Paint { style: self.style(role), depth: self.depth }
+    }
+
+    /// Load a theme file, a missing file being the default theme. The
+    /// depth is used unless the file sets one.
+    pub fn load(path: &Path, depth: Depth) -> Result<Theme, ThemeError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Theme::read(&text, depth),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(Theme::default().with_depth(depth)),
+            Err(err) => Err(ThemeError::Io(err))
+        }
+    }
+
+    /// Read a theme: an optional `preset` to start from and `depth`, then a
+    /// table for each role to change, with its `fg` and `bg` colors and its
+    /// `bold`, `underline` and `reverse` attributes.
+    pub fn read(text: &str, depth: Depth) -> Result<Theme, ThemeError> {
+        let invalid = |key: &str, value: &toml::Value| ThemeError::Invalid(key.to_string(), value.to_string());
+        let table = match text.parse::<toml::Value>() {
+            Ok(toml::Value::Table(table)) => table,
+            Ok(value) => return Err(invalid("theme", &value)),
+            Err(err) => return Err(ThemeError::Syntax(err.to_string()))
+        };
+
+        let mut theme = match table.get("preset") {
+            Some(value) => value.as_str().and_then(Theme::preset).ok_or_else(|| invalid("preset", value))?,
+            None => Theme::default()
+        };
+        theme.depth = match table.get("depth") {
+            Some(value) => value.as_str().and_then(Depth::parse).ok_or_else(|| invalid("depth", value))?,
+            None => depth
+        };
+
+        for (key, value) in table.iter().filter(|&(key, _)| key != "preset" && key != "depth") {
+            let role = match ROLES.iter().find(|&&(_, name)| name == key) {
+                Some(&(role, _)) => role,
+                None => return Err(ThemeError::Invalid("role".to_string(), key.clone()))
+            };
+            let fields = match *value {
+                toml::Value::Table(ref fields) => fields,
+                _ => return Err(invalid(key, value))
+            };
+
+            let mut style = theme.style(role);
+            for (field, value) in fields.iter() {
+                let key = format!("{}.{}", key, field);
+                match (field.as_str(), value) {
+                    ("fg", value) => style.fg = color_of(value).ok_or_else(|| invalid(&key, value))?,
+                    ("bg", value) => style.bg = color_of(value).ok_or_else(|| invalid(&key, value))?,
+                    ("bold", &toml::Value::Boolean(on)) => style.bold = on,
+                    ("underline", &toml::Value::Boolean(on)) => style.underline = on,
+                    ("reverse", &toml::Value::Boolean(on)) => style.reverse = on,
+                    _ => return Err(invalid(&key, value))
+                }
+            }
+            theme.styles[role as usize] = style;
+        }
+
+        Ok(theme)
+    }
+}
+
+/// Color of a theme file: a name, `#rrggbb` or a palette index, or
+/// `default` for the terminal one.
+fn color_of(value: &toml::Value) -> Option<Option<Color>> {
+    match *value {
+        toml::Value::String(ref text) if text == "default" => Some(None),
+        toml::Value::String(ref text) => Color::parse(text).map(Some),
+        toml::Value::Integer(index) if (0..256).contains(&index) => Some(Some(Color::Indexed(index as u8))),
+        _ => None
+    }
+}
+
+
+//---
+/// Sequence setting a style from the terminal defaults, so that nothing
+/// of the previous one remains.
+#[derive(Debug, Clone, Copy)]
+pub struct Paint {
+    style: Style,
+    depth: Depth
+}
+
+impl fmt::Display for Paint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let style = &self.style;
+        write!(f, "\x1b[0")?;
+        for &(on, code) in [(style.bold, 1), (style.underline, 4), (style.reverse, 7)].iter() {
+            if on {
+                write!(f, ";{}", code)?;
+            }
+        }
+        if let Some(color) = style.fg {
+            write!(f, ";{}", color.downgrade(self.depth).sgr(30))?;
+        }
+        if let Some(color) = style.bg {
+            write!(f, ";{}", color.downgrade(self.depth).sgr(40))?;
+        }
+        write!(f, "m")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_colors() {
+        assert_eq!(Color::parse("red"), Some(Color::Ansi(1)));
+        assert_eq!(Color::parse("bright-white"), Some(Color::Ansi(15)));
+        assert_eq!(Color::parse("208"), Some(Color::Indexed(208)));
+        assert_eq!(Color::parse("#0072b2"), Some(Color::Rgb(0, 114, 178)));
+        assert_eq!(Color::parse("#0072b"), None);
+        assert_eq!(Color::parse("#00é2b"), None);
+        assert_eq!(Color::parse("pink"), None);
+    }
+
+    #[test]
+    fn downgrade_to_depth() {
+        let orange = Color::Rgb(230, 159, 0);
+        assert_eq!(orange.downgrade(Depth::TrueColor), orange);
+        assert_eq!(orange.downgrade(Depth::Ansi256), Color::Indexed(178));
+        assert_eq!(orange.downgrade(Depth::Ansi16), Color::Ansi(3));
+        assert_eq!(Color::Indexed(196).downgrade(Depth::Ansi16), Color::Ansi(9));
+        assert_eq!(Color::Indexed(244).downgrade(Depth::Ansi16), Color::Ansi(8));
+        assert_eq!(Color::Indexed(4).downgrade(Depth::TrueColor), Color::Ansi(4));
+
+        assert_eq!(Depth::guess(Some("truecolor"), Some("xterm")), Depth::TrueColor);
+        assert_eq!(Depth::guess(None, Some("screen-256color")), Depth::Ansi256);
+        assert_eq!(Depth::guess(None, Some("linux")), Depth::Ansi16);
+    }
+
+    #[test]
+    fn paint_sequences() {
+        let theme = Theme::preset("colorblind").unwrap();
+        assert_eq!(theme.paint(Role::Pending).to_string(), "\x1b[0m");
+        assert_eq!(theme.paint(Role::Correct).to_string(), "\x1b[0;97;46m");
+        assert_eq!(theme.clone().with_depth(Depth::Ansi256).paint(Role::Correct).to_string(), "\x1b[0;97;48;5;25m");
+        assert_eq!(theme.with_depth(Depth::TrueColor).paint(Role::Correct).to_string(), "\x1b[0;97;48;2;0;114;178m");
+
+        let monochrome = Theme::preset("monochrome").unwrap();
+        assert_eq!(monochrome.paint(Role::Incorrect).to_string(), "\x1b[0;1;4;7m");
+    }
+
+    #[test]
+    fn presets() {
+        for name in Theme::preset_names() {
+            assert!(Theme::preset(name).is_some(), "{}", name);
+        }
+        assert!(Theme::preset("neon").is_none());
+
+        // told apart without colors
+        let monochrome = Theme::preset("monochrome").unwrap();
+        let styles: Vec<Style> = ROLES.iter().map(|&(role, _)| monochrome.style(role)).collect();
+        assert!(styles.iter().all(|s| s.fg.is_none() && s.bg.is_none()));
+        for &role in [Role::Current, Role::Correct, Role::Incorrect, Role::Corrected].iter() {
+            assert_ne!(monochrome.style(role), monochrome.style(Role::Pending), "{:?}", role);
+        }
+    }
+
+    #[test]
+    fn read_theme_file() {
+        let text = "preset = \"monochrome\"\ndepth = \"256\"\n\n\
+                    [correct]\nfg = \"green\"\nbold = false\n\n\
+                    [frame]\nfg = 244\n\n\
+                    [status]\nbg = \"#202020\"\nreverse = false\n";
+        let theme = Theme::read(text, Depth::Ansi16).expect("reading failed");
+
+        assert_eq!(theme.depth, Depth::Ansi256);
+        assert_eq!(theme.style(Role::Correct), fg(Color::Ansi(2)));
+        assert_eq!(theme.style(Role::Frame), fg(Color::Indexed(244)));
+        assert_eq!(theme.style(Role::Status), bg(Color::Rgb(32, 32, 32)));
+        // the rest of the preset is kept
+        assert_eq!(theme.style(Role::Current), attrs(false, false, true));
+
+        assert_eq!(Theme::read("", Depth::TrueColor).unwrap(), Theme::default().with_depth(Depth::TrueColor));
+        let error = |text: &str| Theme::read(text, Depth::Ansi16).unwrap_err().to_string();
+        assert_eq!(error("preset = \"neon\""), "theme has an invalid preset: \"neon\"");
+        assert_eq!(error("[cursor]\nbold = true"), "theme has an invalid role: cursor");
+        assert_eq!(error("[correct]\nfg = \"pink\""), "theme has an invalid correct.fg: \"pink\"");
+        assert_eq!(error("[correct]\nbold = 1"), "theme has an invalid correct.bold: 1");
+        assert!(error("[correct").starts_with("theme is malformed"));
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use app::analysis::{Item, Report};
+use app::word::{Bucket, Word};
+use app::word::db::{Database, Query};
+use app::word::random::Rng;
+
+
+// weight of every word, so that words without weak keys still show up
+static VARIETY: f64 = 1.0;
+
+// weight of the weaknesses of a word, against the variety
+static FOCUS: f64 = 8.0;
+
+
+//---
+/// Pick `count` distinct words among the query results, favouring the
+/// words made of the weakest chars and bigrams of the report.
+pub fn bucket(db: &Database, query: &Query, report: &Report, count: usize, seed: u64) -> Bucket {
+    let mut weakness: HashMap<Item, f64> = HashMap::new();
+    for w in report.chars.iter().chain(report.bigrams.iter()) {
+        weakness.insert(w.item, w.score);
+    }
+
+    let mut candidates: Vec<&str> = db.query(query).iter().map(|e| e.word.as_str()).collect();
+    let mut weights: Vec<f64> = candidates.iter().map(|word| weight(word, &weakness)).collect();
+
+    let mut rng = Rng::new(seed);
+    let mut words = Vec::new();
+    while words.len() < count {
+        let i = match rng.weighted(&weights) {
+            Some(i) => i,
+            None => break
+        };
+        words.push(Word::new(candidates.swap_remove(i)));
+        weights.swap_remove(i);
+    }
+    Bucket { words }
+}
+
+/// Mean weakness of the chars and bigrams of a word, so that long words
+/// aren't favoured only for their length.
+fn weight(word: &str, weakness: &HashMap<Item, f64>) -> f64 {
+    let chars: Vec<char> = word.chars().collect();
+    let score = |item| weakness.get(&item).cloned().unwrap_or(0.0);
+
+    let total: f64 = chars.iter().map(|&c| score(Item::Char(c))).sum::<f64>()
+        + chars.windows(2).map(|pair| score(Item::Bigram(pair[0], pair[1]))).sum::<f64>();
+    VARIETY + FOCUS * total / chars.len().max(1) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use app::analysis::Weakness;
+
+    fn sample() -> Database {
+        let text = "@language en\nzoo\nbuzz\nhat\nsun\nmap\ncat\ndog\nrun\nfish\nbird\n";
+        Database::read(text.as_bytes()).unwrap()
+    }
+
+    fn weak_on(c: char) -> Report {
+        Report {
+            chars: vec![Weakness { item: Item::Char(c), samples: 40, error_rate: 0.5, min_error_rate: 0.35,
+                                   latency: None, confidence: 0.8, score: 1.0 }],
+            ..Report::default()
+        }
+    }
+
+    fn raw(bucket: &Bucket) -> Vec<&str> {
+        bucket.words.iter().map(|w| w.raw.as_str()).collect()
+    }
+
+    #[test]
+    fn reproducible_from_seed() {
+        let db = sample();
+        let query = Query::new();
+
+        let a = bucket(&db, &query, &Report::default(), 5, 1);
+        let b = bucket(&db, &query, &Report::default(), 5, 1);
+        assert_eq!(raw(&a), raw(&b));
+        assert_eq!(raw(&a).len(), 5);
+
+        let mut distinct = raw(&a);
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 5);
+
+        // no more words than the query gives
+        assert_eq!(bucket(&db, &query, &Report::default(), 50, 1).words.len(), 10);
+    }
+
+    #[test]
+    fn favour_weak_chars() {
+        let db = sample();
+        let with_z = |b: &Bucket| b.words.iter().filter(|w| w.raw.contains('z')).count();
+
+        let (mut focused, mut plain) = (0, 0);
+        for seed in 1..50 {
+            focused += with_z(&bucket(&db, &Query::new(), &weak_on('z'), 3, seed));
+            plain += with_z(&bucket(&db, &Query::new(), &Report::default(), 3, seed));
+        }
+        assert!(focused > 2 * plain, "{} against {}", focused, plain);
+        // still some variety
+        assert!(focused < 2 * 49);
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::str;
+
+use app::geometry::Geometry;
+use app::layout::{Layout, Level, Symbol};
+use app::word::{Bucket, LoadError, Word};
+
+
+// bundled databases, shipped within the binary
+static BUNDLED: [&str; 2] = [
+    include_str!("../../../data/words/en.words"),
+    include_str!("../../../data/words/fr.words")
+];
+
+// languages of the bundled databases, known without reading them
+pub static LANGUAGES: [&str; 2] = ["en", "fr"];
+
+pub static TAG_ACCENTS: &str = "accents";
+
+
+//---
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub word: String,
+    pub language: String,
+    // 1 for the most frequent word of the language
+    pub rank: usize,
+    pub tags: Vec<String>
+}
+
+impl Entry {
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+
+//---
+/// Filter on the database, results are sorted by rank.
+#[derive(Debug, Default)]
+pub struct Query {
+    language: Option<String>,
+    tags: Vec<String>,
+    without_tags: Vec<String>,
+    chars: Option<String>,
+    limit: Option<usize>
+}
+
+impl Query {
+    pub fn new() -> Query {
+        Query::default()
+    }
+
+    pub fn language(mut self, language: &str) -> Query {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    /// Keep only the words having the tag.
+    #[allow(dead_code)]
+    pub fn tag(mut self, tag: &str) -> Query {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Keep only the words without the tag.
+    #[allow(dead_code)]
+    pub fn without_tag(mut self, tag: &str) -> Query {
+        self.without_tags.push(tag.to_string());
+        self
+    }
+
+    /// Keep only the words made of those characters.
+    pub fn only_chars(mut self, chars: &str) -> Query {
+        self.chars = Some(chars.to_string());
+        self
+    }
+
+    /// Keep only the words typed on the home row of the keyboard, without
+    /// modifier, as `only_chars` does.
+    #[allow(dead_code)]
+    pub fn home_row(self, layout: &Layout, geometry: &Geometry) -> Query {
+        let chars: String = geometry.rows[geometry.home_row].iter()
+            .filter_map(|slot| layout.key(&slot.code))
+            .filter_map(|key| match key.symbol(Level::Base) {
+                Some(Symbol::Char(c)) => Some(c),
+                _ => None
+            })
+            .collect();
+        self.only_chars(&chars)
+    }
+
+    /// Keep the `n` most frequent words.
+    pub fn limit(mut self, n: usize) -> Query {
+        self.limit = Some(n);
+        self
+    }
+
+    fn matches(&self, entry: &Entry) -> bool {
+        self.language.as_ref().is_none_or(|l| *l == entry.language)
+            && self.tags.iter().all(|t| entry.has_tag(t))
+            && !self.without_tags.iter().any(|t| entry.has_tag(t))
+            && self.chars.as_ref().is_none_or(|chars| entry.word.chars().all(|c| chars.contains(c)))
+    }
+}
+
+
+//---
+#[derive(Debug, Default)]
+pub struct Database {
+    entries: Vec<Entry>
+}
+
+impl Database {
+    /// Database shipped with the application.
+    pub fn bundled() -> Database {
+        let mut db = Database::default();
+        for text in BUNDLED.iter() {
+            let bundled = Database::read(text.as_bytes()).expect("bundled database is malformed");
+            db.merge(bundled);
+        }
+        db
+    }
+
+    /// Load a database file, see `Database::read` for the format.
+    pub fn load(path: &Path) -> Result<Database, LoadError> {
+        let file = File::open(path)?;
+        Database::read(BufReader::new(file))
+    }
+
+    /// Read a database.
+    ///
+    /// A `@language <code>` line starts the words of a language, then comes
+    /// one word per line, the most frequent first, optionally followed by
+    /// a tab and comma-separated tags. Lines starting with `#` are comments.
+    pub fn read<R: BufRead>(mut input: R) -> Result<Database, LoadError> {
+        let mut entries = Vec::new();
+        let mut language: Option<String> = None;
+        let mut ranks: HashMap<String, usize> = HashMap::new();
+        let mut buf = Vec::new();
+        let mut line_number = 0;
+
+        loop {
+            buf.clear();
+            if input.read_until(b'\n', &mut buf)? == 0 {
+                break;
+            }
+            line_number += 1;
+
+            let line = str::from_utf8(&buf)
+                .map_err(|_| LoadError::InvalidUtf8(line_number))?
+                .trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix('@') {
+                let mut parts = directive.split_whitespace();
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some("language"), Some(code), None) => language = Some(code.to_string()),
+                    _ => return Err(LoadError::Malformed(line_number))
+                }
+                continue;
+            }
+
+            let language = match language {
+                Some(ref language) => language.clone(),
+                None => return Err(LoadError::Malformed(line_number))
+            };
+
+            let mut fields = line.split('\t');
+            let word = fields.next().expect("not possible").trim();
+            let mut tags: Vec<String> = match fields.next() {
+                Some(tags) => tags.split(',')
+                    .map(|t| t.trim().to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect(),
+                None => Vec::new()
+            };
+            if word.is_empty() || fields.next().is_some() {
+                return Err(LoadError::Malformed(line_number));
+            }
+            if word.chars().any(|c| c.is_alphabetic() && !c.is_ascii()) {
+                tags.push(TAG_ACCENTS.to_string());
+            }
+
+            let rank = ranks.entry(language.clone()).or_insert(0);
+            *rank += 1;
+            entries.push(Entry { word: word.to_string(), language, rank: *rank, tags });
+        }
+
+        match entries.is_empty() {
+            true => Err(LoadError::Empty),
+            false => Ok(Database { entries })
+        }
+    }
+
+    /// Add the entries of another database, as less frequent words
+    /// if their language is already known.
+    pub fn merge(&mut self, other: Database) {
+        let mut known: HashMap<String, usize> = HashMap::new();
+        for entry in self.entries.iter() {
+            let rank = known.entry(entry.language.clone()).or_insert(0);
+            *rank = (*rank).max(entry.rank);
+        }
+        for mut entry in other.entries {
+            entry.rank += known.get(&entry.language).cloned().unwrap_or(0);
+            self.entries.push(entry);
+        }
+    }
+
+    pub fn languages(&self) -> Vec<&str> {
+        let mut languages: Vec<&str> = self.entries.iter().map(|e| e.language.as_str()).collect();
+        languages.sort();
+        languages.dedup();
+        languages
+    }
+
+    pub fn query(&self, query: &Query) -> Vec<&Entry> {
+        let mut found: Vec<&Entry> = self.entries.iter()
+            .filter(|e| query.matches(e))
+            .collect();
+        found.sort_by_key(|e| e.rank);
+        if let Some(n) = query.limit {
+            found.truncate(n);
+        }
+        found
+    }
+
+    #[allow(dead_code)]
+    pub fn bucket(&self, query: &Query) -> Bucket {
+        Bucket {
+            words: self.query(query).iter().map(|e| Word::new(e.word.as_str())).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> Database {
+        let text = "# sample\n@language en\nthe\tcommon\nand\n@language fr\nété\nle\tcommon\n";
+        Database::read(text.as_bytes()).expect("reading failed")
+    }
+
+    #[test]
+    fn rank_per_language() {
+        let db = sample();
+        let found: Vec<(&str, usize)> = db.query(&Query::new().language("fr"))
+            .iter().map(|e| (e.word.as_str(), e.rank)).collect();
+
+        assert_eq!(found, vec![("été", 1), ("le", 2)]);
+        assert_eq!(db.languages(), vec!["en", "fr"]);
+    }
+
+    #[test]
+    fn filter_on_tags() {
+        let db = sample();
+
+        let common = db.bucket(&Query::new().tag("common"));
+        assert_eq!(common.words.iter().map(|w| w.raw.as_str()).collect::<Vec<_>>(), vec!["the", "le"]);
+
+        let accented = db.query(&Query::new().tag(TAG_ACCENTS));
+        assert_eq!(accented.len(), 1);
+        assert_eq!(accented[0].word, "été");
+
+        let plain = db.query(&Query::new().language("fr").without_tag(TAG_ACCENTS));
+        assert_eq!(plain[0].word, "le");
+    }
+
+    #[test]
+    fn filter_on_chars_and_limit() {
+        let db = sample();
+
+        let found = db.query(&Query::new().only_chars("adnelt").limit(1));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].word, "and");
+    }
+
+    #[test]
+    fn filter_on_home_row() {
+        let db = Database::read("@language en\nask\nthe\nsalad\nhut\n".as_bytes()).unwrap();
+        let geometry = Geometry::builtin("ansi-104").unwrap();
+        let words = |layout: &str| -> Vec<String> {
+            let query = Query::new().home_row(&Layout::builtin(layout).unwrap(), &geometry);
+            db.query(&query).iter().map(|e| e.word.clone()).collect()
+        };
+
+        assert_eq!(words("qwerty-us"), vec!["ask", "salad"]);
+        assert_eq!(words("dvorak"), vec!["the", "hut"]);
+    }
+
+    #[test]
+    fn merge_after_known_words() {
+        let mut db = sample();
+        db.merge(Database::read("@language en\nnext\nlast\n".as_bytes()).unwrap());
+
+        let found: Vec<(&str, usize)> = db.query(&Query::new().language("en"))
+            .iter().map(|e| (e.word.as_str(), e.rank)).collect();
+        assert_eq!(found, vec![("the", 1), ("and", 2), ("next", 3), ("last", 4)]);
+    }
+
+    #[test]
+    fn reject_word_without_language() {
+        match Database::read("the\n".as_bytes()) {
+            Err(LoadError::Malformed(line)) => assert_eq!(line, 1),
+            other => panic!("unexpected {:?}", other)
+        }
+    }
+
+    #[test]
+    fn bundled_databases() {
+        let db = Database::bundled();
+
+        assert_eq!(db.languages(), LANGUAGES.to_vec());
+        for &language in LANGUAGES.iter() {
+            let ranks: Vec<usize> = db.query(&Query::new().language(language)).iter().map(|e| e.rank).collect();
+            assert_eq!(ranks, (1..ranks.len() + 1).collect::<Vec<_>>());
+        }
+        assert_eq!(db.query(&Query::new().language("fr").limit(500)).len(), 169);
+        let qwerty = Query::new().language("en").home_row(&Layout::builtin("qwerty-us").unwrap(),
+                                                          &Geometry::builtin("ansi-104").unwrap());
+        assert!(!db.query(&qwerty).is_empty());
+    }
+}
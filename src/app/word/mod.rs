@@ -0,0 +1,237 @@
+use std::error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::ops::Index;
+use std::path::Path;
+use std::str;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub mod adaptive;
+pub mod db;
+pub mod pseudo;
+pub mod random;
+
+
+//---
+#[derive(Debug)]
+pub struct Word {
+    pub raw: String // TODO keep it only visible inside the crate
+}
+
+impl Word {
+    /// Word in its composed form, so that an accented letter typed with a
+    /// single key matches it.
+    pub fn new<S: Into<String>>(s: S) -> Word {
+        Word { raw: s.into().nfc().collect() }
+    }
+
+    /// Characters as seen by the user, a letter and its combining marks
+    /// making a single one.
+    pub fn graphemes(&self) -> Vec<&str> {
+        self.raw.graphemes(true).collect()
+    }
+
+    /// Columns taken on the terminal.
+    pub fn width(&self) -> usize {
+        width(&self.raw)
+    }
+}
+
+/// Columns taken by a text on the terminal, wide chars taking two.
+pub fn width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Longest start of a text within the columns, cut between graphemes.
+pub fn fit(text: &str, columns: usize) -> String {
+    let mut fitted = String::new();
+    for grapheme in text.graphemes(true) {
+        if width(&fitted) + width(grapheme) > columns {
+            break;
+        }
+        fitted.push_str(grapheme);
+    }
+    fitted
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.raw)
+    }
+}
+
+
+//---
+/// Failure to read a word list or a words database.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    // line number (1-based) which is not valid utf-8
+    InvalidUtf8(usize),
+    // line number (1-based) which cannot be understood
+    Malformed(usize),
+    // no word at all, only blank or comment lines
+    Empty
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LoadError::Io(ref err) => write!(f, "cannot read words: {}", err),
+            LoadError::InvalidUtf8(line) => write!(f, "words are not valid utf-8 at line {}", line),
+            LoadError::Malformed(line) => write!(f, "words are malformed at line {}", line),
+            LoadError::Empty => write!(f, "no word found")
+        }
+    }
+}
+
+impl error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            LoadError::Io(ref err) => Some(err),
+            LoadError::InvalidUtf8(_) | LoadError::Malformed(_) | LoadError::Empty => None
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(err: io::Error) -> LoadError {
+        LoadError::Io(err)
+    }
+}
+
+
+//---
+#[derive(Debug)]
+pub struct Bucket {
+    pub words: Vec<Word> // TODO keep it only visible inside the crate
+}
+
+impl Bucket {
+    #[allow(dead_code)]
+    pub fn new<S: Into<String>>(w: Vec<S>) -> Bucket {
+        Bucket {
+            words: w.into_iter()
+                .map(Word::new)
+                .collect::<Vec<Word>>()
+        }
+    }
+
+    /// Load a word list file, see `Bucket::read` for the format.
+    pub fn load(path: &Path) -> Result<Bucket, LoadError> {
+        let file = File::open(path)?;
+        Bucket::read(BufReader::new(file))
+    }
+
+    /// Read a word list: one word per line, blank lines are skipped
+    /// and lines starting with `#` are comments.
+    pub fn read<R: BufRead>(mut input: R) -> Result<Bucket, LoadError> {
+        let mut words = Vec::new();
+        let mut buf = Vec::new();
+        let mut line_number = 0;
+
+        loop {
+            buf.clear();
+            if input.read_until(b'\n', &mut buf)? == 0 {
+                break;
+            }
+            line_number += 1;
+
+            let line = str::from_utf8(&buf)
+                .map_err(|_| LoadError::InvalidUtf8(line_number))?;
+            let word = line.trim();
+            if word.is_empty() || word.starts_with('#') {
+                continue;
+            }
+            words.push(Word::new(word));
+        }
+
+        match words.is_empty() {
+            true => Err(LoadError::Empty),
+            false => Ok(Bucket { words })
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+}
+
+impl Index<usize> for Bucket {
+    type Output = Word;
+
+    fn index(&self, i: usize) -> &Word {
+        &self.words[i]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn read_words_and_skip_comments() {
+        use super::*;
+        let text = "# common words\nthe\n\n  été \n#and\nœuf\n";
+
+        let bucket = Bucket::read(text.as_bytes()).expect("reading failed");
+        let words: Vec<&str> = bucket.words.iter().map(|w| w.raw.as_str()).collect();
+        assert_eq!(words, vec!["the", "été", "œuf"]);
+    }
+
+    #[test]
+    fn reject_invalid_utf8() {
+        use super::*;
+        let bytes: &[u8] = b"ok\nbad\xff\n";
+
+        match Bucket::read(bytes) {
+            Err(LoadError::InvalidUtf8(line)) => assert_eq!(line, 2),
+            other => panic!("unexpected {:?}", other)
+        }
+    }
+
+    #[test]
+    fn reject_empty_list() {
+        use super::*;
+        let text = "# nothing\n\n";
+
+        match Bucket::read(text.as_bytes()) {
+            Err(LoadError::Empty) => (),
+            other => panic!("unexpected {:?}", other)
+        }
+    }
+
+    #[test]
+    fn reject_missing_file() {
+        use super::*;
+
+        match Bucket::load(Path::new("/this/file/does/not/exist")) {
+            Err(LoadError::Io(_)) => (),
+            other => panic!("unexpected {:?}", other)
+        }
+    }
+
+    #[test]
+    fn graphemes_and_width() {
+        use super::*;
+
+        // decomposed accent, composed when possible
+        let word = Word::new("e\u{301}te\u{301}");
+        assert_eq!(word.raw, "\u{e9}t\u{e9}");
+        assert_eq!(word.graphemes(), vec!["\u{e9}", "t", "\u{e9}"]);
+        assert_eq!(word.width(), 3);
+
+        // no composed form
+        let word = Word::new("q\u{301}a");
+        assert_eq!(word.graphemes(), vec!["q\u{301}", "a"]);
+        assert_eq!(word.width(), 2);
+
+        let word = Word::new("漢字");
+        assert_eq!(word.graphemes().len(), 2);
+        assert_eq!(word.width(), 4);
+        assert_eq!(width("«œuf»"), 5);
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use app::word::{Bucket, Word};
+use app::word::random::Rng;
+
+
+// chars of context plus the next one, 3 making trigrams
+static ORDER: usize = 3;
+
+// start and end of a word, never part of the allowed chars
+const BOUNDARY: char = ' ';
+
+static DEFAULT_MIN_LEN: usize = 3;
+static DEFAULT_MAX_LEN: usize = 7;
+
+// chance to use a shorter context, for words less alike the corpus ones
+static BACKOFF: f64 = 0.2;
+
+// attempts to find a new word before giving up, for each word asked
+static ATTEMPTS: usize = 20;
+
+
+//---
+/// Pronounceable words made only of a set of chars, following the n-grams
+/// of a corpus.
+#[derive(Debug)]
+pub struct Generator {
+    chars: Vec<char>,
+    // count of each next char (or boundary) after each context, the
+    // contexts going from none to `ORDER - 1` chars
+    next: BTreeMap<String, BTreeMap<char, usize>>,
+    min_len: usize,
+    max_len: usize
+}
+
+/// Learn the n-grams of the corpus words, ignoring the ones which use a
+/// char out of the set.
+pub fn new<'a, I: IntoIterator<Item=&'a str>>(corpus: I, chars: &str) -> Generator {
+    let allowed: BTreeSet<char> = chars.chars().filter(|&c| c != BOUNDARY).collect();
+    let mut next: BTreeMap<String, BTreeMap<char, usize>> = BTreeMap::new();
+
+    for word in corpus {
+        let padded: Vec<char> = (0..ORDER - 1).map(|_| BOUNDARY)
+            .chain(word.chars())
+            .chain(Some(BOUNDARY))
+            .collect();
+        for end in ORDER - 1..padded.len() {
+            let c = padded[end];
+            if c != BOUNDARY && !allowed.contains(&c) {
+                continue;
+            }
+            for len in 0..ORDER {
+                let context = &padded[end - len..end];
+                if context.iter().any(|&c| c != BOUNDARY && !allowed.contains(&c)) {
+                    break;
+                }
+                *next.entry(context.iter().collect()).or_default().entry(c).or_default() += 1;
+            }
+        }
+    }
+
+    Generator {
+        chars: allowed.into_iter().collect(),
+        next,
+        min_len: DEFAULT_MIN_LEN,
+        max_len: DEFAULT_MAX_LEN
+    }
+}
+
+impl Generator {
+    /// Length of the words, in chars, bounds included.
+    pub fn lengths(mut self, min: usize, max: usize) -> Generator {
+        self.min_len = min.max(1);
+        self.max_len = max.max(self.min_len);
+        self
+    }
+
+    /// `count` distinct words, the same ones for the same seed. There may be
+    /// less if the chars cannot make that much words.
+    pub fn bucket(&self, count: usize, seed: u64) -> Bucket {
+        let mut rng = Rng::new(seed);
+        let mut seen = BTreeSet::new();
+        let mut words = Vec::new();
+
+        if !self.chars.is_empty() {
+            for _ in 0..count * ATTEMPTS {
+                if words.len() >= count {
+                    break;
+                }
+                let word = self.word(&mut rng);
+                if seen.insert(word.clone()) {
+                    words.push(Word::new(word));
+                }
+            }
+        }
+        Bucket { words }
+    }
+
+    fn word(&self, rng: &mut Rng) -> String {
+        let mut padded: Vec<char> = (0..ORDER - 1).map(|_| BOUNDARY).collect();
+        let mut len = 0;
+
+        while len < self.max_len {
+            let can_end = len >= self.min_len;
+            match self.pick(&padded, can_end, rng) {
+                Some(BOUNDARY) => break,
+                Some(c) => padded.push(c),
+                // nothing learnt, any allowed char
+                None => padded.push(self.chars[rng.below(self.chars.len())])
+            }
+            len += 1;
+        }
+        padded[ORDER - 1..].iter().collect()
+    }
+
+    /// Next char after the longest known context, backing off to shorter
+    /// ones, if the corpus knows any.
+    fn pick(&self, padded: &[char], can_end: bool, rng: &mut Rng) -> Option<char> {
+        for len in (0..ORDER).rev() {
+            if len > 0 && rng.unit() < BACKOFF {
+                continue;
+            }
+            let context: String = padded[padded.len() - len..].iter().collect();
+            let candidates: Vec<(char, usize)> = match self.next.get(&context) {
+                Some(next) => next.iter()
+                    .filter(|&(&c, _)| can_end || c != BOUNDARY)
+                    .map(|(&c, &n)| (c, n))
+                    .collect(),
+                None => continue
+            };
+            let weights: Vec<f64> = candidates.iter().map(|&(_, n)| n as f64).collect();
+            if let Some(i) = rng.weighted(&weights) {
+                return Some(candidates[i].0);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static CORPUS: [&str; 8] = ["salade", "dalle", "fade", "les", "des", "elle", "alfa", "assez"];
+
+    fn raw(bucket: &Bucket) -> Vec<&str> {
+        bucket.words.iter().map(|w| w.raw.as_str()).collect()
+    }
+
+    #[test]
+    fn only_allowed_chars() {
+        let generator = new(CORPUS.iter().cloned(), "asdfel").lengths(2, 5);
+        let bucket = generator.bucket(30, 3);
+
+        assert_eq!(bucket.words.len(), 30);
+        for word in raw(&bucket) {
+            assert!(word.chars().all(|c| "asdfel".contains(c)), "{}", word);
+            assert!((2..=5).contains(&word.chars().count()), "{}", word);
+        }
+
+        // distinct words
+        let mut distinct = raw(&bucket);
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 30);
+    }
+
+    #[test]
+    fn follow_the_corpus() {
+        // `x` is not in the corpus, and no word starts with `ll`
+        let generator = new(CORPUS.iter().cloned(), "asdelx");
+        for word in raw(&generator.bucket(20, 5)) {
+            assert!(!word.contains('x'), "{}", word);
+            assert!(!word.starts_with("ll"), "{}", word);
+        }
+
+        // chars unknown to the corpus are still used
+        let unknown = new(CORPUS.iter().cloned(), "xy");
+        assert!(raw(&unknown.bucket(5, 1)).iter().all(|w| w.chars().all(|c| c == 'x' || c == 'y')));
+        assert!(new(CORPUS.iter().cloned(), "").bucket(5, 1).words.is_empty());
+    }
+
+    #[test]
+    fn reproducible_from_seed() {
+        let generator = new(CORPUS.iter().cloned(), "asdfel");
+        assert_eq!(raw(&generator.bucket(10, 9)), raw(&generator.bucket(10, 9)));
+        assert_ne!(raw(&generator.bucket(10, 9)), raw(&generator.bucket(10, 10)));
+    }
+}
@@ -0,0 +1,90 @@
+// any non-zero state works, this one only avoids a zero seed
+static DEFAULT_STATE: u64 = 0x9e37_79b9_7f4a_7c15;
+
+
+//---
+/// Small xorshift64* generator, reproducible from its seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng {
+            state: match seed {
+                0 => DEFAULT_STATE,
+                seed => seed
+            }
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Uniform number in `[0, 1)`.
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform index in `[0, n)`, `n` being positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        debug_assert!(n > 0, "empty range");
+        (self.unit() * n as f64) as usize
+    }
+
+    /// Index picked with a probability proportional to its weight, none
+    /// if all weights are null.
+    pub fn weighted(&mut self, weights: &[f64]) -> Option<usize> {
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = self.unit() * total;
+        for (i, &weight) in weights.iter().enumerate() {
+            if target < weight {
+                return Some(i);
+            }
+            target -= weight;
+        }
+        // rounding errors, fallback on the last possible one
+        weights.iter().rposition(|&w| w > 0.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+
+        let from_a: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let from_b: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        let from_c: Vec<u64> = (0..5).map(|_| c.next_u64()).collect();
+        assert_eq!(from_a, from_b);
+        assert_ne!(from_a, from_c);
+        assert_ne!(Rng::new(0).next_u64(), 0);
+    }
+
+    #[test]
+    fn weighted_choice() {
+        let mut rng = Rng::new(7);
+        let mut hits = [0; 3];
+        for _ in 0..1000 {
+            hits[rng.weighted(&[1.0, 0.0, 3.0]).unwrap()] += 1;
+        }
+
+        assert_eq!(hits[1], 0);
+        assert!(hits[2] > 2 * hits[0], "{:?}", hits);
+        assert_eq!(rng.weighted(&[0.0, 0.0]), None);
+        assert!((0..100).all(|_| rng.below(3) < 3));
+    }
+}
@@ -1,126 +1,438 @@
+extern crate libc;
 extern crate termion;
+extern crate toml;
+extern crate unicode_normalization;
+extern crate unicode_segmentation;
+extern crate unicode_width;
 
 #[allow(unused_imports)]
 use std::io::{stdout, stdin, Read, Write};
 
-use termion::raw::IntoRawMode;
-
 #[macro_use]
 mod app;
 
+use app::cli::Command;
+use app::config::Config;
+use app::engine::{Change, Input};
+use app::error::AppError;
+
+
+// how often the terminal size and the status bar clock are checked, while
+// no key is typed
+static POLLING: std::time::Duration = std::time::Duration::from_millis(100);
+
+
+fn to_input(key: termion::event::Key) -> Option<Input> {
+    match key {
+        termion::event::Key::Char(char_) => Some(Input::Char(char_)),
+        termion::event::Key::Backspace => Some(Input::Backspace),
+        termion::event::Key::Esc => Some(Input::Escape),
+        // any other thing that isn't a simple char
+        _ => None
+    }
+}
+
+/// Room inside the margins and the frame of the terminal, the last row
+/// being left to the status bar.
+fn area_on_terminal(config: &Config, term: app::ui::Dim) -> app::ui::BoundingBox {
+    let inset = config.margin + config.gap;
+    let dim = term.shrink(inset * 2, inset * 2 + 1);
+    app::ui::BoundingBox { x: 1 + inset, y: 1 + inset, w: dim.w, h: dim.h }
+}
+
+/// Words, and the keyboard if there is room for it, aligned in the area of
+/// the terminal.
+fn layout_on_terminal(bucket: &app::word::Bucket, keyboard: &app::ui::keyboard::Keyboard,
+                      feedback: &dyn app::ui::feedback::Feedback, config: &Config,
+                      term: app::ui::Dim) -> Result<app::ui::Layout, app::ui::LayoutError> {
+    let area = area_on_terminal(config, term);
+    let constraint = app::ui::Constraint {
+        origin: area.pos(),
+        dim: area.dim().into(),
+        align: config.align.clone(),
+        line_height: feedback.line_height()
+    };
+    app::ui::layout_with_keyboard(&constraint, bucket, &keyboard.dim(), config.gap + 1)
+        .or_else(|_| app::ui::layout(&constraint, bucket))
+}
+
+/// Paint the whole screen, words in their current state.
+fn draw(layout: &app::ui::Layout, keyboard: &app::ui::keyboard::Keyboard, engine: &app::engine::Engine,
+        feedback: &dyn app::ui::feedback::Feedback, theme: &app::ui::theme::Theme, gap: u16,
+        output: &mut dyn Write) -> std::io::Result<()> {
+    write!(output, "{}", termion::clear::All)?;
+    app::ui::render::write_frame(&layout.frame, gap, feedback.frame_role(engine.elapsed()), theme, output)?;
+    app::ui::render::write_session(layout, engine, feedback, theme, output)?;
+    app::ui::render::write_keyboard(layout, keyboard, engine, theme, output)
+}
+
+/// Paint the frame again if its role is no longer the painted one.
+fn update_frame(layout: &app::ui::Layout, engine: &app::engine::Engine, feedback: &dyn app::ui::feedback::Feedback,
+                theme: &app::ui::theme::Theme, gap: u16, painted: &mut app::ui::theme::Role,
+                output: &mut dyn Write) -> std::io::Result<()> {
+    let role = feedback.frame_role(engine.elapsed());
+    if role == *painted {
+        return Ok(());
+    }
+    *painted = role;
+    app::ui::render::write_frame(&layout.frame, gap, role, theme, output)
+}
+
+fn load_history() -> Result<app::history::History, AppError> {
+    match app::history::default_path() {
+        Some(path) => app::history::History::load(&path).map_err(|err| AppError::History(path, err)),
+        None => Ok(app::history::History::default())
+    }
+}
+
+fn load_progress() -> Result<app::lesson::Progress, AppError> {
+    match app::lesson::default_path() {
+        Some(path) => app::lesson::Progress::load(&path).map_err(|err| AppError::Progress(path, err)),
+        None => Ok(app::lesson::Progress::default())
+    }
+}
+
+/// The config file given, or the default one, then the options of the
+/// command line.
+fn load_config(cli: &app::cli::Cli) -> Result<Config, AppError> {
+    let mut config = match cli.config.clone().or_else(app::config::default_path) {
+        // unlike the default file, a file given has to be there
+        Some(path) if cli.config.is_some() && !path.exists() => return Err(AppError::MissingConfig(path)),
+        Some(path) => Config::load(&path).map_err(|err| AppError::Config(path, err))?,
+        None => Config::default()
+    };
+    cli.apply(&mut config)?;
+    Ok(config)
+}
+
+/// The preset of the config, or else the theme file, or the default theme,
+/// for the colors of the terminal.
+fn load_theme(config: &Config) -> Result<app::ui::theme::Theme, AppError> {
+    let depth = app::ui::theme::Depth::detect();
+    let theme = match (config.theme.as_ref().and_then(|preset| app::ui::theme::Theme::preset(preset)),
+                       app::ui::theme::default_path()) {
+        (Some(theme), _) => theme,
+        (None, Some(path)) => return app::ui::theme::Theme::load(&path, depth).map_err(|err| AppError::Theme(path, err)),
+        (None, None) => app::ui::theme::Theme::default()
+    };
+    Ok(theme.with_depth(depth))
+}
+
+/// The builtin layout of the config, or else its XKB symbols file.
+fn load_layout(config: &Config) -> Result<app::layout::Layout, AppError> {
+    if let Some(layout) = app::layout::Layout::builtin(&config.layout) {
+        return Ok(layout);
+    }
+    let (path, variant) = app::layout::xkb::split_variant(&config.layout);
+    app::layout::xkb::load(path, variant).map_err(|err| AppError::Layout(path.to_path_buf(), err))
+}
+
+/// Name of the layout of the config for the history, an XKB symbols file
+/// being made absolute to be found again from anywhere.
+fn layout_name(config: &Config) -> String {
+    if app::layout::Layout::builtin(&config.layout).is_some() {
+        return config.layout.clone();
+    }
+    let (path, variant) = app::layout::xkb::split_variant(&config.layout);
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    match variant {
+        Some(variant) => format!("{}({})", path.display(), variant),
+        None => path.display().to_string()
+    }
+}
+
+/// The builtin geometry of the config.
+fn load_geometry(config: &Config) -> Result<app::geometry::Geometry, AppError> {
+    app::geometry::Geometry::builtin(&config.geometry)
+        .ok_or_else(|| AppError::InvalidConfig("geometry".to_string(), config.geometry.clone()))
+}
+
+/// A layout of the history, builtin or XKB symbols file, if still there.
+fn find_layout(name: &str) -> Option<app::layout::Layout> {
+    app::layout::Layout::builtin(name).or_else(|| {
+        let (path, variant) = app::layout::xkb::split_variant(name);
+        app::layout::xkb::load(path, variant).ok()
+    })
+}
+
+/// The database of the config, or the bundled one.
+fn load_database(config: &Config) -> Result<app::word::db::Database, AppError> {
+    match config.database {
+        Some(ref path) => app::word::db::Database::load(path).map_err(|err| AppError::Words(path.clone(), err)),
+        None => Ok(app::word::db::Database::bundled())
+    }
+}
+
+fn seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() ^ u64::from(d.subsec_nanos()))
+        .unwrap_or(0)
+}
+
+fn report(config: &Config) -> Result<(), AppError> {
+    let history = load_history()?;
+    let report = app::analysis::analyze(&history, &load_geometry(config)?, &find_layout);
+    let theme = load_theme(config)?;
 
-fn main() {
-    // init
-    let stdin = stdin();
     let stdout = stdout();
-    let stdout = stdout.lock();
-    let mut stdout = stdout.into_raw_mode().unwrap();
-    write!(&mut stdout, "{}{}{}{}{}",
-           termion::clear::All,
-           app::ui::Pos { x: 1, y: 1 },
-           termion::color::Bg(termion::color::Reset),
-           termion::color::Fg(termion::color::Reset),
-           termion::cursor::Hide
-    ).unwrap();
+    let mut stdout = stdout.lock();
+    write!(&mut stdout, "{}", termion::clear::All)?;
+    let corner = 1 + config.margin + config.gap;
+    let frame = app::ui::BoundingBox { x: corner, y: corner, w: 0, h: 0 };
+    app::ui::render::write_report(&frame, config.gap, &report, 5, &theme, &mut stdout)?;
+    // leave the cursor under the frame
+    writeln!(&mut stdout, "{}\n\n", termion::style::Reset)?;
+    Ok(())
+}
+
+fn main() {
+    app::ui::terminal::install_hooks();
+    // a panic has already restored the terminal, its message is told as
+    // any other error
+    let result = std::panic::catch_unwind(run).unwrap_or_else(|_| {
+        Err(AppError::Panic(app::ui::terminal::panic_message().unwrap_or_else(|| "panic".to_string())))
+    });
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        std::process::exit(err.exit_code());
+    }
+}
+
+fn run() -> Result<(), AppError> {
+    let cli = app::cli::parse(std::env::args_os().skip(1).map(|arg| arg.to_string_lossy().into_owned()))?;
+    match cli.command {
+        Command::Help => {
+            print!("{}", app::cli::USAGE);
+            return Ok(());
+        }
+        Command::Layouts => {
+            for name in app::layout::Layout::builtin_names() {
+                println!("{}", name);
+            }
+            return Ok(());
+        }
+        Command::Train | Command::Lesson | Command::Stats => ()
+    }
+
+    let config = load_config(&cli)?;
+    if cli.command == Command::Stats {
+        return report(&config);
+    }
+
+    let theme = load_theme(&config)?;
+    let mut feedback = app::ui::feedback::new(&config.feedback)
+        .ok_or_else(|| AppError::InvalidConfig("feedback".to_string(), config.feedback.clone()))?;
+    let keyboard = app::ui::keyboard::new(load_layout(&config)?, load_geometry(&config)?);
+
+    // lesson, on the layout of the config
+    let mut lesson = match cli.command {
+        Command::Lesson => Some((app::lesson::Plan::for_layout(&keyboard.layout), load_progress()?)),
+        _ => None
+    };
 
     // input
-    let f_term_size = || app::ui::term_dim().shrink(2, 2).into();
-    let bucket = app::word::Bucket::new(vec!["test", "this", "and", "the", "next"]);
-
-    // setup
-    let count = bucket.len();
-    let ui_constraint = app::ui::Constraint {
-        origin: app::ui::Pos { x: 1, y: 1 }.shift(1, 1),
-        dim: f_term_size(),
-        align: app::ui::Alignment::centered()
+    let (bucket, source) = match (&lesson, config.words.as_ref()) {
+        (&Some((ref plan, ref progress)), _) => {
+            let stage = progress.stage(plan);
+            let report = app::analysis::analyze(&load_history().unwrap_or_default(), &keyboard.geometry, &find_layout);
+            let bucket = plan.bucket(stage, &load_database(&config)?, &report, config.count, seed());
+            (bucket, format!("lesson:{}:{}", plan.layout, stage + 1))
+        }
+        (&None, Some(path)) => match app::word::Bucket::load(path) {
+            Ok(bucket) => (bucket, path.to_string_lossy().into_owned()),
+            Err(err) => return Err(AppError::Words(path.clone(), err))
+        },
+        (&None, None) => {
+            // an unreadable history is reported when saving the session
+            let report = app::analysis::analyze(&load_history().unwrap_or_default(), &keyboard.geometry, &find_layout);
+            let database = load_database(&config)?;
+            let query = app::word::db::Query::new().language(&config.language).limit(config.count.max(200));
+            if database.query(&query).is_empty() {
+                let languages = database.languages().into_iter().map(String::from).collect();
+                return Err(AppError::Language(config.language.clone(), languages));
+            }
+            let bucket = app::word::adaptive::bucket(&database, &query, &report, config.count, seed());
+            let origin = match config.database {
+                Some(ref path) => path.to_string_lossy().into_owned(),
+                None => "bundled".to_string()
+            };
+            (bucket, format!("{}:{}", origin, config.language))
+        }
     };
-    let layout = app::ui::layout(&ui_constraint, &bucket)
-        .expect("cannot layout word in those constraints");
-    let status_bar_starter = app::ui::Pos { x: 1, y: app::ui::term_dim().h - 1 };
+
+    // setup, before the terminal is taken, so that a failure is shown plainly
+    let mut layout = layout_on_terminal(&bucket, &keyboard, feedback.as_ref(), &config, app::ui::term_dim()?)
+        .map_err(|_| AppError::TooSmall)?;
+    let mut term_size = termion::terminal_size()?;
+    let mut status = app::ui::status::new(app::ui::Pos { x: 1, y: term_size.1 }, term_size.0);
+    if let Some((ref plan, ref progress)) = lesson {
+        let stage = progress.stage(plan);
+        status.set_label(&format!("Lesson {}, stage {}/{}: {}",
+                                  plan.layout, stage + 1, plan.stages.len(), plan.stages[stage].name));
+    }
+
+    // init, the terminal being restored when the session is dropped
+    let mut stdout = app::ui::terminal::start()?;
 
     // init print
-    app::ui::brush::write_frame2(layout.frame, 2, &mut stdout).unwrap();
-    write_iter!(&mut stdout, "{}{}", layout.positions, bucket.words).unwrap();
-
-    // main loop
-    'mainloop: for i_curr in 0..count {
-        let exercise = app::exercise::new(&bucket[i_curr]);
-        let mut progress: usize = 0;
-
-        // initial key colorisation
-        {
-            write!(stdout, "{}{}{}",
-                   termion::color::Bg(termion::color::Magenta),
-                   layout.positions[i_curr],
-                   exercise[progress]
-            ).unwrap();
+    let mut engine = app::engine::new(&bucket, config.error_policy, config.word_policy)
+        .with_dead_keys(keyboard.layout.dead_keys());
+    draw(&layout, &keyboard, &engine, feedback.as_ref(), &theme, config.gap, &mut stdout)?;
+    let mut frame_role = feedback.frame_role(engine.elapsed());
+    status.write(&theme, &mut stdout)?;
+    stdout.flush()?;
+
+    // keys are read aside, so that the terminal size and the signals can be
+    // watched meanwhile
+    let (sender, events) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        use termion::input::TermRead;
+        for event in stdin().events() {
+            if sender.send(event).is_err() {
+                break;
+            }
         }
+    });
 
-        stdout.flush().unwrap();
+    // main loop, painting nothing while the words don't fit
+    let mut fits = true;
+    while !engine.is_over() {
+        if let Some(signal) = app::ui::terminal::interruption() {
+            return Err(AppError::Interrupted(signal));
+        }
 
-        use termion::input::TermRead;
-        let input = stdin.lock();
-        'word: for event in input.events() {
-            match event.expect("no event") {
-                termion::event::Event::Key(key) => {
-                    match key {
-                        termion::event::Key::Esc => {
-                            write!(stdout, "{}Aborted game", status_bar_starter).unwrap();
-                            break 'mainloop;
+        let event = match events.recv_timeout(POLLING) {
+            Ok(event) => event,
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                let size = termion::terminal_size()?;
+                if size != term_size {
+                    term_size = size;
+                    status.place(app::ui::Pos { x: 1, y: size.1 }, size.0);
+                    // the previous layout is kept, but not painted, until the words
+                    // fit again and the whole screen is drawn
+                    match layout_on_terminal(&bucket, &keyboard, feedback.as_ref(), &config, app::ui::term_dim()?) {
+                        Ok(new_layout) => {
+                            layout = new_layout;
+                            fits = true;
+                            draw(&layout, &keyboard, &engine, feedback.as_ref(), &theme, config.gap, &mut stdout)?;
+                            frame_role = feedback.frame_role(engine.elapsed());
                         }
-                        termion::event::Key::Char(char_) => {
-                            let ref pos = layout.positions[i_curr];
-                            let curr_progress = progress;
-
-                            match char_ == exercise[progress] {
-                                true => {
-                                    write!(stdout, "{}", termion::color::Bg(
-                                        termion::color::Green
-                                    )).unwrap();
-                                    progress += 1;
-                                }
-                                false => {
-                                    write!(stdout, "{}", termion::color::Bg(
-                                        termion::color::Red
-                                    )).unwrap();
-                                }
-                            }
-
-                            write!(stdout, "{}{}", pos.shift(curr_progress as i16, 0), char_).unwrap();
-
-                            if progress < exercise.len() {
-                                write!(stdout, "{}{}{}",
-                                       pos.shift(progress as i16, 0),
-                                       termion::color::Bg(termion::color::Magenta),
-                                       exercise[progress]
-                                ).unwrap();
-                            }
-                            else {
-                                break 'word;
-                            }
+                        Err(_) => {
+                            fits = false;
+                            write!(stdout, "{}", termion::clear::All)?;
+                            status.post(app::ui::status::Level::Error, "Terminal too small", engine.elapsed());
                         }
-                        // any other thing that isn't a simple char
-                        _ => ()
+                    }
+                    status.write(&theme, &mut stdout)?;
+                } else {
+                    if fits {
+                        update_frame(&layout, &engine, feedback.as_ref(), &theme, config.gap, &mut frame_role, &mut stdout)?;
+                    }
+                    if status.update(engine.record(), engine.elapsed()) {
+                        status.write(&theme, &mut stdout)?;
                     }
                 }
-                termion::event::Event::Mouse(me) => {
-                    write!(stdout, "{}Mouse event! (=> {:?})", status_bar_starter, me).unwrap();
+                stdout.flush()?;
+                continue;
+            }
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break
+        };
+
+        match event? {
+            // the terminal doesn't send SIGINT in raw mode
+            termion::event::Event::Key(termion::event::Key::Ctrl('c')) => {
+                return Err(AppError::Interrupted(libc::SIGINT));
+            }
+            termion::event::Event::Key(key) => {
+                let changes = match to_input(key) {
+                    Some(input) => engine.feed(input),
+                    None => Vec::new()
+                };
+                feedback.notice(&changes, engine.elapsed());
+
+                if fits {
+                    update_frame(&layout, &engine, feedback.as_ref(), &theme, config.gap, &mut frame_role, &mut stdout)?;
+                    app::ui::render::write_changes(&layout, &engine, &changes, feedback.as_ref(), &theme, &mut stdout)?;
+                    app::ui::render::write_keyboard(&layout, &keyboard, &engine, &theme, &mut stdout)?;
                 }
-                termion::event::Event::Unsupported(x) => {
-                    write!(stdout, "{}Unsupported event occurred (=> {:?})", status_bar_starter, x).unwrap();
+
+                if changes.contains(&Change::Aborted) {
+                    status.post(app::ui::status::Level::Info, "Aborted game", engine.elapsed());
                 }
             }
+            termion::event::Event::Mouse(me) => {
+                status.post(app::ui::status::Level::Warning, &format!("Mouse event! (=> {:?})", me), engine.elapsed());
+            }
+            termion::event::Event::Unsupported(x) => {
+                status.post(app::ui::status::Level::Warning, &format!("Unsupported event occurred (=> {:?})", x), engine.elapsed());
+            }
+        }
+
+        status.update(engine.record(), engine.elapsed());
+        status.write(&theme, &mut stdout)?;
+        stdout.flush()?;
+    }
+
+    // summary
+    let summary = app::stats::summarize(engine.record());
+    write!(&mut stdout, "{}{}", termion::clear::All, termion::style::Reset)?;
+    let area = area_on_terminal(&config, app::ui::term_dim()?);
+    app::ui::render::write_summary(&layout.frame, &area, config.gap, &summary, &theme, &mut stdout)?;
+
+    // lesson, moving to the next stage once the current one is passed
+    let progressed = match lesson {
+        Some((ref plan, ref mut progress)) if engine.session().is_finished() => {
+            let stage = progress.stage(plan);
+            let required = &plan.stages[stage];
+            match progress.update(plan, &summary) {
+                true => status.post(app::ui::status::Level::Info, &format!("Stage {} passed", stage + 1), engine.elapsed()),
+                false => status.post(app::ui::status::Level::Warning,
+                                     &format!("Stage {} needs {:.0} % accuracy and {:.0} wpm",
+                                              stage + 1, required.min_accuracy * 100.0, required.min_wpm),
+                                     engine.elapsed())
+            }
+            match app::lesson::default_path() {
+                Some(path) => progress.save(&path).map_err(|err| AppError::Progress(path, err)),
+                None => Ok(())
+            }
+        }
+        _ => Ok(())
+    };
 
-            stdout.flush().unwrap();
+    // history, of finished sessions only, saved before waiting on the
+    // summary so that nothing is lost if the wait is interrupted
+    let saved = match (engine.session().is_finished(), app::history::default_path()) {
+        (true, Some(path)) => {
+            let date = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let entry = app::history::Entry::new(date, &layout_name(&config), &source, engine.record());
+            app::history::History::append(&path, &entry).map_err(|err| AppError::History(path, err))
+        }
+        _ => Ok(())
+    };
+
+    status.write(&theme, &mut stdout)?;
+    stdout.flush()?;
+    loop {
+        if let Some(signal) = app::ui::terminal::interruption() {
+            return Err(AppError::Interrupted(signal));
+        }
+        match events.recv_timeout(POLLING) {
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
+            Ok(_) | Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break
         }
     }
 
     // finisher
-    write!(&mut stdout, "{}{}{}{}\n",
-           app::ui::Pos { x: 1, y: app::ui::term_dim().h - 1 },
-           termion::cursor::Show,
-           termion::color::Bg(termion::color::Reset),
-           termion::color::Fg(termion::color::Reset)
-    ).unwrap();
-    stdout.flush().unwrap();
+    stdout.close(app::ui::Pos { x: 1, y: app::ui::term_dim()?.h.saturating_sub(1) })?;
+
+    // saving errors don't stop the session, they are only told
+    for err in saved.err().into_iter().chain(progressed.err()) {
+        eprintln!("{}", err);
+    }
+    Ok(())
 }